@@ -3,10 +3,12 @@
 use {
     crate::{
         error::Error,
-        mapper::Mapper,
-        marker::{self, AccessorTypeSpecifier, Readable, Writable},
+        integer::IntReg,
+        mapper::{CacheMode, Identity, Mapper, Mapping},
+        marker::{self, AccessorTypeSpecifier, Downgrade, Readable, Writable, Zeroable},
+        single, volatile,
     },
-    core::{fmt, hash::Hash, marker::PhantomData, mem, ptr},
+    core::{cmp::Ordering, fmt, hash::Hash, marker::PhantomData, mem, ops::Range, ptr::NonNull},
 };
 
 /// An alis of [`Array`]
@@ -26,8 +28,10 @@ pub type WriteOnly<T, M> = Generic<T, M, marker::WriteOnly>;
 ///
 /// When accessing to an element of the array, the index starts from 0.
 ///
-/// `T` does not need to implement [`Copy`]. However, be careful that [`Generic::read_volatile_at`]
-/// creates and [`Generic::write_volatile_at`] writes a bitwise copy of a value.
+/// `T` does not need to implement [`Copy`]. However, [`Generic::read_volatile_at`] requires `T` to
+/// implement [`Copy`] because it creates a bitwise copy of a value. Use [`Generic::read_clone_at`]
+/// for types implementing only [`Clone`]. Also be careful that [`Generic::write_volatile_at`]
+/// writes a bitwise copy of a value.
 ///
 /// # Examples
 ///
@@ -53,7 +57,7 @@ pub type WriteOnly<T, M> = Generic<T, M, marker::WriteOnly>;
 /// let mut a = unsafe { accessor::Array::<u32, M>::new(0x1000, 10, mapper) };
 ///
 /// // Read the 3rd element of the array.
-/// a.read_volatile_at(3);
+/// let _ = a.read_volatile_at(3);
 ///
 /// // Write 42 as the 5th element of the array.
 /// a.write_volatile_at(5, 42);
@@ -70,9 +74,9 @@ where
 {
     virt: usize,
     len: usize,
+    mapping: Mapping<M>,
     _marker: PhantomData<T>,
     _read_write: PhantomData<A>,
-    mapper: M,
 }
 #[allow(clippy::len_without_is_empty)] // Array is never empty.
 impl<T, M, A> Generic<T, M, A>
@@ -80,6 +84,12 @@ where
     M: Mapper,
     A: AccessorTypeSpecifier,
 {
+    /// The maximum length of an array whose size in bytes does not overflow [`usize`].
+    pub const MAX_LEN: usize = match mem::size_of::<T>() {
+        0 => usize::MAX,
+        size => usize::MAX / size,
+    };
+
     /// Creates an accessor to `[T; len]` at the physical address `phys_base`.
     ///
     /// # Safety
@@ -87,27 +97,47 @@ where
     /// The caller must ensure the following conditions:
     /// - The array at the physical address `phys_base` is valid.
     /// - Any other accessors except the one returned by this method must not access the array
-    /// while the returned one lives.
+    ///   while the returned one lives.
     ///
     /// # Panics
     ///
     /// This method panics if
+    /// - `T` is a zero-sized type.
     /// - `phys_base` is not aligned as the type `T` requires.
     /// - `len == 0`.
-    pub unsafe fn new(phys_base: usize, len: usize, mut mapper: M) -> Self {
-        assert!(super::is_aligned::<T>(phys_base));
-        assert_ne!(len, 0);
+    /// - `len > Self::MAX_LEN`.
+    pub unsafe fn new(phys_base: usize, len: usize, mapper: M) -> Self {
+        Self::assert_valid(phys_base, len);
 
-        let bytes = mem::size_of::<T>() * len;
-        let virt = mapper.map(phys_base, bytes).get();
+        let mapping = Mapping::new(phys_base, mem::size_of::<T>() * len, mapper);
 
-        Self {
-            virt,
-            len,
-            _marker: PhantomData,
-            _read_write: PhantomData,
-            mapper,
-        }
+        Self::from_raw_parts(mapping.virt(), len, mapping)
+    }
+
+    /// Creates an accessor to `[T; len]` at the physical address `phys_base`, mapping the memory
+    /// with the memory type `cache`.
+    ///
+    /// The memory is mapped by [`Mapper::map_cacheable`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::new`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics on the same conditions as [`Generic::new`].
+    #[allow(clippy::too_many_arguments)] // `cache` is needed in addition to `new`'s.
+    pub unsafe fn new_with_cache(
+        phys_base: usize,
+        len: usize,
+        mapper: M,
+        cache: CacheMode,
+    ) -> Self {
+        Self::assert_valid(phys_base, len);
+
+        let mapping = Mapping::new_with_cache(phys_base, mem::size_of::<T>() * len, mapper, cache);
+
+        Self::from_raw_parts(mapping.virt(), len, mapping)
     }
 
     /// Creates an accessor to `[T; len]` at the physical address `phys_base`.
@@ -117,16 +147,22 @@ where
     /// The caller must ensure the following conditions:
     /// - The array at the physical address `phys_base` is valid.
     /// - Any other accessors except the one returned by this method must not access the array
-    /// while the returned one lives.
+    ///   while the returned one lives.
     ///
     /// # Errors
     ///
     /// This method may return an error.
+    /// - [`Error::ZeroSized`] - `T` is a zero-sized type.
     /// - [`Error::NotAligned`] - `phys_base` is not aligned as the type `T` requires.
     /// - [`Error::EmptyArray`] - `len == 0`
+    /// - [`Error::SizeOverflow`] - `len > Self::MAX_LEN`
     pub unsafe fn try_new(phys_base: usize, len: usize, mapper: M) -> Result<Self, Error> {
-        if len == 0 {
+        if mem::size_of::<T>() == 0 {
+            Err(Error::ZeroSized)
+        } else if len == 0 {
             Err(Error::EmptyArray)
+        } else if len > Self::MAX_LEN {
+            Err(Error::SizeOverflow)
         } else if super::is_aligned::<T>(phys_base) {
             Ok(Self::new(phys_base, len, mapper))
         } else {
@@ -137,302 +173,2681 @@ where
         }
     }
 
+    /// Creates an accessor to `[T; len]` at the physical address `phys_base`, ensuring that the
+    /// array is at most `max_bytes` bytes.
+    ///
+    /// This prevents mapping a huge region by a broken `len`, e.g. one read from a corrupted
+    /// descriptor.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::try_new`].
+    ///
+    /// # Errors
+    ///
+    /// This method may return an error.
+    /// - [`Error::SizeOverflow`] - the size of the array overflows.
+    /// - [`Error::TooLarge`] - the array is larger than `max_bytes` bytes.
+    /// - Any error that [`Generic::try_new`] returns.
+    #[allow(clippy::too_many_arguments)] // `max_bytes` is needed in addition to `try_new`'s.
+    pub unsafe fn new_with_max_bytes(
+        phys_base: usize,
+        len: usize,
+        max_bytes: usize,
+        mapper: M,
+    ) -> Result<Self, Error> {
+        let requested = required_bytes::<T>(len).ok_or(Error::SizeOverflow)?;
+
+        if requested > max_bytes {
+            Err(Error::TooLarge {
+                requested,
+                max: max_bytes,
+            })
+        } else {
+            Self::try_new(phys_base, len, mapper)
+        }
+    }
+
+    /// Creates an accessor to `[T; len]` at the physical address `phys_base`, and writes zero to
+    /// all the bytes of the array.
+    ///
+    /// The array is zeroed regardless of the type specifier, so this method can create a
+    /// read-only accessor to a freshly cleared array, e.g. a descriptor table.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::new`], and that the array is
+    /// writable.
+    ///
+    /// # Panics
+    ///
+    /// This method panics on the same conditions as [`Generic::new`].
+    pub unsafe fn new_zeroed(phys_base: usize, len: usize, mapper: M) -> Self
+    where
+        T: Zeroable,
+    {
+        let a = Self::new(phys_base, len, mapper);
+
+        for i in 0..mem::size_of::<T>() * len {
+            volatile::write(a.virt + i, 0_u8);
+        }
+
+        a
+    }
+
+    /// Creates an accessor to `[T; len]` at the already mapped virtual address `virt`.
+    ///
+    /// `virt` is still passed to `mapper` so that the mapper can keep track of the region. Use a
+    /// mapper which maps an address into itself, such as [`Identity`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The array at the virtual address `virt` is valid and mapped while the returned accessor
+    ///   lives.
+    /// - Any other accessors except the one returned by this method must not access the array
+    ///   while the returned one lives.
+    ///
+    /// # Errors
+    ///
+    /// This method may return an error.
+    /// - [`Error::EmptyArray`] - `len == 0`
+    /// - [`Error::SizeOverflow`] - the size or the end address of the array overflows.
+    /// - [`Error::NotAligned`] - `virt` is not aligned as the type `T` requires.
+    pub unsafe fn try_new_from_virt(virt: usize, len: usize, mapper: M) -> Result<Self, Error> {
+        if len != 0
+            && required_bytes::<T>(len)
+                .and_then(|bytes| virt.checked_add(bytes))
+                .is_none()
+        {
+            Err(Error::SizeOverflow)
+        } else {
+            Self::try_new(virt, len, mapper)
+        }
+    }
+
+    /// Creates an accessor to `[T; len]` at the physical address `phys_base`, ensuring that the
+    /// array ends at or before the physical address `max_end`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::try_new`].
+    ///
+    /// # Errors
+    ///
+    /// This method may return an error.
+    /// - [`Error::SizeOverflow`] - the end address of the array overflows.
+    /// - [`Error::OutOfRegion`] - the array ends after `max_end`.
+    /// - Any error that [`Generic::try_new`] returns.
+    #[allow(clippy::too_many_arguments)] // `max_end` is needed in addition to `try_new`'s.
+    pub unsafe fn try_new_within(
+        phys_base: usize,
+        len: usize,
+        max_end: usize,
+        mapper: M,
+    ) -> Result<Self, Error> {
+        let end = required_bytes::<T>(len)
+            .and_then(|bytes| phys_base.checked_add(bytes))
+            .ok_or(Error::SizeOverflow)?;
+
+        if end > max_end {
+            Err(Error::OutOfRegion { end, max_end })
+        } else {
+            Self::try_new(phys_base, len, mapper)
+        }
+    }
+
+    /// Creates an accessor to `[T; len]` at the physical address `ptr` points to.
+    ///
+    /// The address of `ptr` is regarded as a physical address and is mapped with `mapper` as
+    /// [`Generic::new`] does. Use [`Identity`] if `ptr` is already accessible.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::new`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if
+    /// - `ptr` is not aligned as the type `T` requires.
+    /// - `len == 0`.
+    pub unsafe fn from_non_null(ptr: NonNull<T>, len: usize, mapper: M) -> Self {
+        Self::new(ptr.as_ptr() as usize, len, mapper)
+    }
+
+    /// Creates an accessor to `[T; len]` at the first address which is equal to or greater than
+    /// `phys_base` and is aligned as the type `T` requires.
+    ///
+    /// This method returns the accessor and the number of padding bytes skipped to align
+    /// `phys_base`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The array at the aligned physical address is valid.
+    /// - Any other accessors except the one returned by this method must not access the array
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if
+    /// - aligning `phys_base` overflows.
+    /// - `len == 0`.
+    pub unsafe fn new_aligned_up(phys_base: usize, len: usize, mapper: M) -> (Self, usize) {
+        let alignment = mem::align_of::<T>();
+        let padding = (alignment - phys_base % alignment) % alignment;
+        let aligned = phys_base
+            .checked_add(padding)
+            .expect("Aligning `phys_base` overflowed.");
+
+        (Self::new(aligned, len, mapper), padding)
+    }
+
+    /// Creates an accessor to the array at the virtual address `virt` in `mapping`.
+    ///
+    /// The returned accessor unmaps the region of `mapping` when dropped.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `[T; len]` at `virt` is valid and is in the region of `mapping`,
+    /// `virt` is aligned as the type `T` requires, and `len != 0`.
+    pub(crate) unsafe fn from_raw_parts(virt: usize, len: usize, mapping: Mapping<M>) -> Self {
+        debug_assert!(mapping.contains(virt, mem::size_of::<T>() * len));
+
+        Self {
+            virt,
+            len,
+            mapping,
+            _marker: PhantomData,
+            _read_write: PhantomData,
+        }
+    }
+
+    /// Panics if `[T; len]` at `phys_base` cannot be accessed, as documented in [`Generic::new`].
+    fn assert_valid(phys_base: usize, len: usize) {
+        assert_ne!(mem::size_of::<T>(), 0, "`T` must not be a zero-sized type.");
+        assert!(super::is_aligned::<T>(phys_base));
+        assert_ne!(len, 0);
+        assert!(len <= Self::MAX_LEN);
+    }
+
     /// Returns the length of the array.
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns the virtual address of the first element.
+    pub fn virt(&self) -> usize {
+        self.virt
+    }
+
+    /// Returns the memory type passed to [`Generic::new_with_cache`], or [`None`] if the accessor
+    /// is created by another constructor.
+    pub fn cache_mode(&self) -> Option<CacheMode> {
+        self.mapping.cache_mode()
+    }
+
+    /// Returns the range of the raw pointers spanning the array.
+    ///
+    /// The end pointer points one past the last element.
+    pub fn as_ptr_range(&self) -> Range<*const T> {
+        self.virt as *const T..self.addr(self.len()) as *const T
+    }
+
     fn addr(&self, i: usize) -> usize {
         self.virt + mem::size_of::<T>() * i
     }
-}
-impl<T, M, A> Generic<T, M, A>
-where
-    M: Mapper,
-    A: Readable,
-{
-    /// Reads the `i`th element from the address that the accessor points.
+    /// Converts the accessor into a read-only one.
+    ///
+    /// This method does not remap the memory region.
+    pub fn into_read_only(self) -> Generic<T, M, marker::ReadOnly>
+    where
+        A: Downgrade<marker::ReadOnly>,
+    {
+        self.into_type_specifier()
+    }
+
+    /// Converts the accessor into a write-only one.
+    ///
+    /// This method does not remap the memory region.
+    pub fn into_write_only(self) -> Generic<T, M, marker::WriteOnly>
+    where
+        A: Downgrade<marker::WriteOnly>,
+    {
+        self.into_type_specifier()
+    }
+
+    /// Converts the accessor into an accessor to `[[T; N]; self.len() / N]`.
+    ///
+    /// This method does not remap the memory region. The conversion is sound because `[T; N]`
+    /// has the same alignment as `T`, and the array of `self.len() / N` chunks occupies exactly
+    /// the same memory region as the original array.
     ///
     /// # Panics
     ///
-    /// This method will panic if `i >= self.len()`
-    pub fn read_volatile_at(&self, i: usize) -> T {
-        assert!(i < self.len());
+    /// This method panics if `N == 0` or `self.len()` is not a multiple of `N`.
+    pub fn as_chunks<const N: usize>(self) -> Generic<[T; N], M, A> {
+        assert_ne!(N, 0);
+        assert_eq!(self.len() % N, 0);
 
-        // SAFETY: `Accessor::new_array` ensures that `self.addr(i)` is aligned properly.
-        unsafe { ptr::read_volatile(self.addr(i) as *const _) }
+        let len = self.len() / N;
+        self.cast(len)
     }
 
-    /// Alias of [`Generic::read_volatile_at`].
-    #[deprecated(since = "0.3.1", note = "use `read_volatile_at`")]
-    pub fn read_at(&self, i: usize) -> T {
-        self.read_volatile_at(i)
+    /// Calls `f` with each index in `indices` and an accessor to the element at the index.
+    ///
+    /// The elements are visited in the order of `indices`. Each accessor lives only during the
+    /// call of `f`, so accessors to the same element never coexist even if `indices` has
+    /// duplicates.
+    ///
+    /// # Panics
+    ///
+    /// This method panics when it reaches an index `i` with `i >= self.len()`. The elements
+    /// before the index are already visited at that time.
+    pub fn for_indices_mut<I, F>(&mut self, indices: I, mut f: F)
+    where
+        I: IntoIterator<Item = usize>,
+        F: FnMut(usize, &mut single::Bounded<'_, T, M, A>),
+    {
+        for i in indices {
+            assert!(i < self.len());
+
+            // SAFETY: `self.addr(i)` is in the mapped region and is aligned properly. `self` is
+            // borrowed mutably, so no other accessors access the element.
+            let mut e = unsafe { single::Bounded::new(self.addr(i)) };
+            f(i, &mut e);
+        }
     }
-}
-impl<T, M, A> Generic<T, M, A>
-where
-    M: Mapper,
-    A: Writable,
-{
-    /// Writes `v` as the `i`th element to the address that the accessor points to.
+
+    /// Returns the label of the region this accessor points to, given by [`Mapper::describe`].
+    pub fn debug_describe(&self) -> Option<&'static str> {
+        self.mapping.mapper().describe(self.virt)
+    }
+
+    /// Consumes the accessor without unmapping the memory region, and returns the virtual address
+    /// of the array.
+    ///
+    /// This is useful for a memory region which must be mapped while the program runs. Neither
+    /// [`Mapper::unmap`] is called nor the mapper is dropped.
+    pub fn leak(self) -> usize {
+        let virt = self.virt;
+        mem::forget(self);
+        virt
+    }
+
+    /// Converts the accessor into an accessor to the `i`th element.
+    ///
+    /// Since mappers may not support unmapping a part of a region, the returned accessor keeps
+    /// the mapping of the whole array and unmaps it when dropped.
     ///
     /// # Panics
     ///
-    /// This method will panic if `i >= self.len()`
-    pub fn write_volatile_at(&mut self, i: usize, v: T) {
+    /// This method panics if `i >= self.len()`.
+    pub fn into_single_at(self, i: usize) -> single::Generic<T, M, A>
+    where
+        T: Copy,
+    {
         assert!(i < self.len());
 
-        // SAFETY: `Accessor::new_array` ensures that `self.addr(i)` is aligned properly.
-        unsafe {
-            ptr::write_volatile(self.addr(i) as *mut _, v);
+        // SAFETY: `self.addr(i)` is in the region of the mapping and is aligned properly.
+        unsafe { single::Generic::from_raw_parts(self.addr(i), self.mapping) }
+    }
+
+    /// Returns two views of the elements in the ranges `a` and `b`, which must not overlap.
+    ///
+    /// # Errors
+    ///
+    /// This method may return an error.
+    /// - [`Error::OutOfBounds`] - a range exceeds the array. It holds the last index of the range.
+    /// - [`Error::OverlappingIndices`] - the ranges overlap.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the start of a range is greater than its end.
+    #[allow(clippy::type_complexity)] // A pair of views is clearer than a type alias.
+    pub fn get_disjoint_ranges_mut(
+        &mut self,
+        a: Range<usize>,
+        b: Range<usize>,
+    ) -> Result<(SubArray<'_, T, M, A>, SubArray<'_, T, M, A>), Error> {
+        assert!(a.start <= a.end);
+        assert!(b.start <= b.end);
+
+        let end = a.end.max(b.end);
+
+        if end > self.len() {
+            Err(Error::OutOfBounds {
+                index: end - 1,
+                len: self.len(),
+            })
+        } else if a.start < b.end && b.start < a.end {
+            Err(Error::OverlappingIndices)
+        } else {
+            // SAFETY: The ranges are in the array and do not overlap, and `self` is borrowed
+            // mutably while the views live.
+            Ok(unsafe {
+                (
+                    SubArray::new(self.virt + mem::size_of::<T>() * a.start, a.len()),
+                    SubArray::new(self.virt + mem::size_of::<T>() * b.start, b.len()),
+                )
+            })
         }
     }
 
-    /// Alias of [`Generic::write_volatile_at`].
-    #[deprecated(since = "0.3.1", note = "use `write_volatile_at`")]
-    pub fn write_at(&mut self, i: usize, v: T) {
-        self.write_volatile_at(i, v);
+    /// Returns a view which regards the array as a grid of `rows` rows and `cols` columns.
+    ///
+    /// This is the same as [`Generic::as_grid`], but the view can also write elements if the
+    /// accessor is writable.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `rows * cols != self.len()`.
+    pub fn as_grid_mut(&mut self, rows: usize, cols: usize) -> Grid<'_, T, M, A> {
+        assert_eq!(rows.checked_mul(cols), Some(self.len()));
+
+        // SAFETY: The view covers the array, and `self` is borrowed mutably while it lives.
+        Grid::new(unsafe { SubArray::new(self.virt, self.len()) }, cols)
     }
-}
-impl<T, M, A> Generic<T, M, A>
-where
-    M: Mapper,
-    A: Readable + Writable,
-{
-    /// Updates the `i`th element that the accessor points by reading it, modifying it, and writing it.
-    pub fn update_volatile_at<U>(&mut self, i: usize, f: U)
+
+    fn into_type_specifier<B>(self) -> Generic<T, M, B>
     where
-        U: FnOnce(&mut T),
+        B: AccessorTypeSpecifier,
     {
-        let mut v = self.read_volatile_at(i);
-        f(&mut v);
-        self.write_volatile_at(i, v);
+        let len = self.len();
+        self.cast(len)
     }
 
-    /// Alias of [`Generic::update_volatile_at`].
-    #[deprecated(since = "0.3.1", note = "use `update_volatile_at`")]
-    pub fn update_at<U>(&mut self, i: usize, f: U)
+    /// Reinterprets the accessor as an accessor to `[U; len]`.
+    ///
+    /// The caller must ensure that `[U; len]` fits in the mapped region and `U` is aligned
+    /// properly.
+    fn cast<U, B>(self, len: usize) -> Generic<U, M, B>
     where
-        U: FnOnce(&mut T),
+        B: AccessorTypeSpecifier,
     {
-        self.update_volatile_at(i, f);
+        // SAFETY: The caller ensures that `[U; len]` at `self.virt` is in the mapped region.
+        unsafe { Generic::from_raw_parts(self.virt, len, self.mapping) }
     }
 }
-impl<T, M, A> fmt::Debug for Generic<T, M, A>
+impl<T, A> Generic<T, Identity, A>
 where
-    T: fmt::Debug,
-    M: Mapper,
-    A: Readable,
+    A: AccessorTypeSpecifier,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self).finish()
+    /// Creates an accessor to `[T; len]` at `offset` bytes from `region_virt`, the virtual address
+    /// of an already mapped region.
+    ///
+    /// This method does not call [`Mapper::map`]. Mapping a region once and creating accessors
+    /// with this method avoids mapping the same region multiple times.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The region containing the array is mapped while the returned accessor lives.
+    /// - The array at `region_virt + offset` is valid.
+    /// - Any other accessors except the one returned by this method must not access the array
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if
+    /// - `region_virt + offset` overflows.
+    /// - `region_virt + offset` is not aligned as the type `T` requires.
+    /// - `len == 0`.
+    #[must_use]
+    pub unsafe fn new_in_region(region_virt: usize, offset: usize, len: usize) -> Self {
+        let virt = region_virt
+            .checked_add(offset)
+            .expect("`region_virt + offset` overflowed.");
+
+        Self::new(virt, len, Identity)
     }
 }
-impl<T, M, A> PartialEq for Generic<T, M, A>
-where
-    T: PartialEq,
-    M: Mapper,
-    A: Readable,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.into_iter()
-            .zip(other)
-            .map(|(a, b)| a.eq(&b))
-            .all(|x| x)
+#[cfg(any(test, feature = "test-utils"))]
+impl<T> ReadWrite<T, Identity> {
+    /// Creates an accessor to the elements of `slice` with [`Identity`].
+    ///
+    /// This method is intended to be used in tests, so that they do not need to implement
+    /// [`Mapper`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `slice` outlives the returned accessor, and it is not accessed
+    /// except via the accessor while the accessor lives. The accessor does not borrow `slice`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `slice` is empty.
+    pub unsafe fn from_slice(slice: &mut [T]) -> Self {
+        Self::new(slice.as_mut_ptr() as usize, slice.len(), Identity)
     }
 }
-impl<T, M, A> Eq for Generic<T, M, A>
+impl<T, M, A> Generic<T, M, A>
 where
-    T: Eq,
     M: Mapper,
     A: Readable,
 {
-}
-impl<T, M, A> Hash for Generic<T, M, A>
-where
-    T: Hash,
-    M: Mapper,
-    A: Readable,
+    /// Reads the `i`th element from the address that the accessor points.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `i >= self.len()`
+    #[must_use]
+    pub fn read_volatile_at(&self, i: usize) -> T
+    where
+        T: Copy,
+    {
+        assert!(i < self.len());
+
+        // SAFETY: `Accessor::new_array` ensures that `self.addr(i)` is aligned properly.
+        unsafe { volatile::read(self.addr(i)) }
+    }
+
+    /// Reads the `i`th element.
+    ///
+    /// This is the same as [`Generic::read_volatile_at`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `i >= self.len()`
+    #[must_use]
+    pub fn value_at(&self, i: usize) -> T
+    where
+        T: Copy,
+    {
+        self.read_volatile_at(i)
+    }
+
+    /// Returns an accessor to the `i`th element.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::OutOfBounds`] holding `i` and the length of the array if
+    /// `i >= self.len()`.
+    pub fn checked_at(
+        &self,
+        i: usize,
+    ) -> Result<single::Bounded<'_, T, M, marker::ReadOnly>, Error> {
+        if i < self.len() {
+            // SAFETY: `self.addr(i)` is in the mapped region and is aligned properly.
+            Ok(unsafe { single::Bounded::new(self.addr(i)) })
+        } else {
+            Err(Error::OutOfBounds {
+                index: i,
+                len: self.len(),
+            })
+        }
+    }
+
+    /// Reads the elements from the first one and calls `f` with each of them until `f` returns
+    /// an error.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the first error returned by `f`.
+    pub fn try_for_each<E, F>(&self, f: F) -> Result<(), E>
+    where
+        T: Copy,
+        F: FnMut(T) -> Result<(), E>,
+    {
+        self.into_iter().try_for_each(f)
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`, assuming that
+    /// the array is partitioned by `pred`.
+    ///
+    /// This method reads `O(log n)` elements. If the array is not partitioned, the returned
+    /// value is unspecified.
+    pub fn partition_point<F>(&self, mut pred: F) -> usize
+    where
+        T: Copy,
+        F: FnMut(&T) -> bool,
+    {
+        let mut left = 0;
+        let mut right = self.len();
+
+        while left < right {
+            let mid = left + (right - left) / 2;
+
+            if pred(&self.read_volatile_at(mid)) {
+                left = mid + 1;
+            } else {
+                right = mid;
+            }
+        }
+
+        left
+    }
+
+    /// Searches the element whose key extracted by `f` is `key`, assuming that the array is
+    /// sorted by the key.
+    ///
+    /// This method reads `O(log n)` elements. Like [`slice::binary_search_by_key`], this method
+    /// returns `Ok` with the index of the matching element if found. If multiple elements match,
+    /// any one of them may be returned.
+    ///
+    /// # Errors
+    ///
+    /// This method returns `Err` with the index where a matching element could be inserted while
+    /// keeping the order if no element matches.
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        T: Copy,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let mut left = 0;
+        let mut right = self.len();
+
+        while left < right {
+            let mid = left + (right - left) / 2;
+
+            match f(&self.read_volatile_at(mid)).cmp(key) {
+                Ordering::Less => left = mid + 1,
+                Ordering::Greater => right = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(left)
+    }
+
+    /// Reads the elements from the first one, and writes the values produced by `f` to `out`.
+    ///
+    /// `f` is called with the mutable state initialized with `init` and each element. This
+    /// method returns the number of written values, which is the smaller one of `out.len()` and
+    /// `self.len()`.
+    #[allow(clippy::too_many_arguments)] // Mirrors `Iterator::scan` with an output buffer.
+    pub fn scan_into<B, F>(&self, init: B, out: &mut [B], mut f: F) -> usize
+    where
+        T: Copy,
+        B: Copy,
+        F: FnMut(&mut B, T) -> B,
+    {
+        let mut state = init;
+        let mut n = 0;
+
+        for (o, v) in out.iter_mut().zip(self) {
+            *o = f(&mut state, v);
+            n += 1;
+        }
+
+        n
+    }
+
+    /// Reads the `i`th element by cloning the value that the accessor points to.
+    ///
+    /// Unlike [`Generic::read_volatile_at`], this method does not require `T` to implement
+    /// [`Copy`]. The value on memory is cloned, and the bitwise copy used to clone it is never
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `i >= self.len()`
+    #[must_use]
+    pub fn read_clone_at(&self, i: usize) -> T
+    where
+        T: Clone,
+    {
+        assert!(i < self.len());
+
+        // SAFETY: `Accessor::new_array` ensures that `self.addr(i)` is aligned properly.
+        let v = mem::ManuallyDrop::new(unsafe { volatile::read::<T>(self.addr(i)) });
+        T::clone(&v)
+    }
+
+    /// Returns an iterator over the elements starting from the `start`th one.
+    ///
+    /// Unlike skipping elements of the iterator returned by `into_iter`, this method does not
+    /// read the skipped elements.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `start > self.len()`.
+    pub fn iter_from(&self, start: usize) -> Iter<'_, T, M, A>
+    where
+        T: Copy,
+    {
+        assert!(start <= self.len());
+
+        Iter { a: self, i: start }
+    }
+
+    /// Splits the array into `N` contiguous read-only views whose lengths differ by at most one.
+    ///
+    /// The former views are the longer ones if the length of the array is not a multiple of `N`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `N == 0` or `N > self.len()`.
+    pub fn split_into_ref<const N: usize>(&self) -> [SubArray<'_, T, M, marker::ReadOnly>; N] {
+        assert_ne!(N, 0);
+        assert!(N <= self.len());
+
+        let base = self.len() / N;
+        let rem = self.len() % N;
+
+        core::array::from_fn(|i| {
+            let start = base * i + i.min(rem);
+            let len = base + usize::from(i < rem);
+
+            // SAFETY: The views are in the array, and they are read-only.
+            unsafe { SubArray::new(self.addr(start), len) }
+        })
+    }
+
+    /// Returns a read-only view which regards the array as a grid of `rows` rows and `cols`
+    /// columns.
+    ///
+    /// The element at the row `r` and the column `c` is the `r * cols + c`th element.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `rows * cols != self.len()`.
+    pub fn as_grid(&self, rows: usize, cols: usize) -> Grid<'_, T, M, marker::ReadOnly> {
+        assert_eq!(rows.checked_mul(cols), Some(self.len()));
+
+        // SAFETY: The view covers the array, and it is read-only.
+        Grid::new(unsafe { SubArray::new(self.virt, self.len()) }, cols)
+    }
+
+    /// Returns a read-only view of the elements in `range`.
+    ///
+    /// # Errors
+    ///
+    /// This method may return an error.
+    /// - [`Error::EmptyRange`] - `range.start >= range.end`
+    /// - [`Error::OutOfBounds`] - `range.end > self.len()`. It holds the last index of the range.
+    pub fn try_subslice(
+        &self,
+        range: Range<usize>,
+    ) -> Result<SubArray<'_, T, M, marker::ReadOnly>, Error> {
+        if range.start >= range.end {
+            Err(Error::EmptyRange)
+        } else if range.end > self.len() {
+            Err(Error::OutOfBounds {
+                index: range.end - 1,
+                len: self.len(),
+            })
+        } else {
+            // SAFETY: The range is in the array, and the view is read-only.
+            Ok(unsafe { SubArray::new(self.addr(range.start), range.len()) })
+        }
+    }
+
+    /// Returns a read-only view of the elements of `self` followed by the ones of `other`, or
+    /// `None` if `other` does not start just after the end of `self` in the virtual memory.
+    ///
+    /// The view borrows both accessors, so the two regions stay mapped while it lives.
+    pub fn try_concat<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> Option<SubArray<'a, T, M, marker::ReadOnly>> {
+        if self.addr(self.len()) == other.virt {
+            // SAFETY: Both arrays are mapped and adjacent, and the view is read-only.
+            Some(unsafe { SubArray::new(self.virt, self.len() + other.len()) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the read-only accessors to the elements.
+    ///
+    /// Unlike the iterator returned by `into_iter`, this iterator does not read the elements by
+    /// itself, and it can also iterate the elements in reverse order.
+    pub fn iter_accessors(&self) -> AccessorIter<'_, T, M, A> {
+        AccessorIter {
+            a: self,
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// Returns an iterator over the pairs of the `2i`th and the `2i + 1`th elements.
+    ///
+    /// This is useful to read values split into two registers, such as the lower and the higher
+    /// 32 bits of a 64-bit value.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the length of the array is odd.
+    pub fn iter_pairs(&self) -> impl Iterator<Item = (T, T)> + '_
+    where
+        T: Copy,
+    {
+        assert_eq!(self.len() % 2, 0);
+
+        (0..self.len() / 2).map(move |i| {
+            (
+                self.read_volatile_at(2 * i),
+                self.read_volatile_at(2 * i + 1),
+            )
+        })
+    }
+
+    /// Reads all elements into an array of length `N`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::LengthMismatch`] if `N != self.len()`.
+    pub fn try_read_all<const N: usize>(&self) -> Result<[T; N], Error>
+    where
+        T: Copy,
+    {
+        if N == self.len() {
+            Ok(core::array::from_fn(|i| self.read_volatile_at(i)))
+        } else {
+            Err(Error::LengthMismatch {
+                expected: N,
+                got: self.len(),
+            })
+        }
+    }
+
+    /// Reads up to `buf.len()` elements into `buf`, and returns the initialized part of `buf`.
+    pub fn collect_into<'b>(&self, buf: &'b mut [mem::MaybeUninit<T>]) -> &'b mut [T]
+    where
+        T: Copy,
+    {
+        let n = self.len().min(buf.len());
+
+        for (i, slot) in buf[..n].iter_mut().enumerate() {
+            slot.write(self.read_volatile_at(i));
+        }
+
+        // SAFETY: The first `n` elements of `buf` are initialized.
+        unsafe { core::slice::from_raw_parts_mut(buf.as_mut_ptr().cast::<T>(), n) }
+    }
+
+    /// Returns `true` if the elements are sorted in ascending order.
+    ///
+    /// This method reads the elements in order, and stops reading at the first element which is
+    /// less than the previous one.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd + Copy,
+    {
+        let mut prev = self.read_volatile_at(0);
+
+        self.iter_from(1).all(|v| {
+            let sorted = prev <= v;
+            prev = v;
+            sorted
+        })
+    }
+
+    /// Reads elements in order, writes the ones for which `pred` returns `true` into `out`, and
+    /// returns the number of the written elements.
+    ///
+    /// Each element is read only once. This method stops reading when `out` becomes full.
+    pub fn filter_collect_into<F>(&self, mut pred: F, out: &mut [T]) -> usize
+    where
+        T: Copy,
+        F: FnMut(&T) -> bool,
+    {
+        let mut n = 0;
+
+        for i in 0..self.len() {
+            if n == out.len() {
+                break;
+            }
+
+            let v = self.read_volatile_at(i);
+            if pred(&v) {
+                out[n] = v;
+                n += 1;
+            }
+        }
+
+        n
+    }
+
+    /// Reads elements in order and returns the first non-`None` result of `f`.
+    ///
+    /// The elements after the found one are not read.
+    pub fn find_map<U, F>(&self, f: F) -> Option<U>
+    where
+        T: Copy,
+        F: FnMut(T) -> Option<U>,
+    {
+        self.iter_from(0).find_map(f)
+    }
+
+    /// Returns an iterator which reads the elements after checking that each of them is still
+    /// mapped with [`Mapper::is_mapped`].
+    ///
+    /// The iterator yields [`Error::NotMapped`] for the first element which is not mapped, and
+    /// then stops.
+    pub fn try_iter(&self) -> TryIter<'_, T, M, A>
+    where
+        T: Copy,
+    {
+        TryIter { a: self, i: 0 }
+    }
+
+    /// Reads all elements and returns the sum of them, wrapping around at the boundary of the type.
+    pub fn wrapping_sum(&self) -> T
+    where
+        T: IntReg,
+    {
+        self.iter_from(0).fold(T::ZERO, T::wrapping_add)
+    }
+
+    /// Reads all elements and returns the product of them, wrapping around at the boundary of the
+    /// type.
+    pub fn wrapping_product(&self) -> T
+    where
+        T: IntReg,
+    {
+        self.iter_from(0).fold(T::ONE, T::wrapping_mul)
+    }
+
+    /// Reads elements and returns the sum of them, or `None` if it overflows.
+    ///
+    /// The elements after the one causing the overflow are not read.
+    pub fn checked_sum(&self) -> Option<T>
+    where
+        T: IntReg,
+    {
+        self.iter_from(0).try_fold(T::ZERO, T::checked_add)
+    }
+
+    /// Reads elements and returns the sum of them, or `None` if it overflows.
+    ///
+    /// This is the same as [`Generic::checked_sum`], and the fallible counterpart of summing up the
+    /// elements with [`Iterator::sum`].
+    pub fn try_sum(&self) -> Option<T>
+    where
+        T: IntReg,
+    {
+        self.checked_sum()
+    }
+
+    /// Returns an iterator over the elements before the `end`th one in reverse order.
+    ///
+    /// The elements at and after the `end`th one are not read.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `end > self.len()`.
+    pub fn iter_rev_from(&self, end: usize) -> impl Iterator<Item = T> + '_
+    where
+        T: Copy,
+    {
+        assert!(end <= self.len());
+
+        (0..end).rev().map(move |i| self.read_volatile_at(i))
+    }
+
+    /// Reads all elements and returns the number of the ones for which `pred` returns `true`.
+    pub fn count<F>(&self, mut pred: F) -> usize
+    where
+        T: Copy,
+        F: FnMut(&T) -> bool,
+    {
+        self.into_iter().filter(|v| pred(v)).count()
+    }
+
+    /// Alias of [`Generic::read_volatile_at`].
+    #[deprecated(since = "0.3.1", note = "use `read_volatile_at`")]
+    pub fn read_at(&self, i: usize) -> T
+    where
+        T: Copy,
+    {
+        self.read_volatile_at(i)
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: Writable,
 {
-    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        for e in self {
-            e.hash(state);
+    /// Writes `v` as the `i`th element to the address that the accessor points to.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `i >= self.len()`
+    pub fn write_volatile_at(&mut self, i: usize, v: T) {
+        assert!(i < self.len());
+
+        // SAFETY: `Accessor::new_array` ensures that `self.addr(i)` is aligned properly.
+        unsafe {
+            volatile::write(self.addr(i), v);
+        }
+    }
+
+    /// Copies the elements of `src` to this array, and returns the number of the copied elements.
+    ///
+    /// This method copies the first `min(self.len(), src.len())` elements by reading and writing
+    /// each of them.
+    pub fn copy_from_accessor<M2, B>(&mut self, src: &Generic<T, M2, B>) -> usize
+    where
+        T: Copy,
+        M2: Mapper,
+        B: Readable,
+    {
+        let n = self.len().min(src.len());
+
+        for i in 0..n {
+            self.write_volatile_at(i, src.read_volatile_at(i));
+        }
+
+        n
+    }
+
+    /// Writes the elements of `values` to the array in order.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `N != self.len()`. No elements are written in this case.
+    pub fn write_all<const N: usize>(&mut self, values: [T; N])
+    where
+        T: Copy,
+    {
+        assert_eq!(N, self.len());
+
+        for (i, v) in values.iter().enumerate() {
+            self.write_volatile_at(i, *v);
+        }
+    }
+
+    /// Writes `value` to each element whose index is in `range`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `range.end > self.len()`. No elements are written in this case.
+    pub fn fill_range(&mut self, range: Range<usize>, value: T)
+    where
+        T: Copy,
+    {
+        assert!(range.end <= self.len());
+
+        for i in range {
+            self.write_volatile_at(i, value);
+        }
+    }
+
+    /// Writes `value` to the elements at `start`, `start + step`, `start + 2 * step`, and so on
+    /// within the bounds of the array.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `step == 0`.
+    #[allow(clippy::too_many_arguments)] // The start, the step, and the value are all needed.
+    pub fn fill_step(&mut self, start: usize, step: usize, value: T)
+    where
+        T: Copy,
+    {
+        assert_ne!(step, 0);
+
+        for i in (start..self.len()).step_by(step) {
+            self.write_volatile_at(i, value);
+        }
+    }
+
+    /// Writes `pattern` repeatedly from the first element, i.e. `pattern[i % pattern.len()]` to
+    /// the `i`th element.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `pattern` is empty.
+    pub fn fill_cyclic(&mut self, pattern: &[T])
+    where
+        T: Copy,
+    {
+        assert!(!pattern.is_empty());
+
+        for (i, v) in (0..self.len()).zip(pattern.iter().copied().cycle()) {
+            self.write_volatile_at(i, v);
+        }
+    }
+
+    /// Returns the range of the raw mutable pointers spanning the array.
+    ///
+    /// The end pointer points one past the last element.
+    pub fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        self.virt as *mut T..self.addr(self.len()) as *mut T
+    }
+
+    /// Alias of [`Generic::write_volatile_at`].
+    #[deprecated(since = "0.3.1", note = "use `write_volatile_at`")]
+    pub fn write_at(&mut self, i: usize, v: T) {
+        self.write_volatile_at(i, v);
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: Readable + Writable,
+{
+    /// Updates the `i`th element that the accessor points by reading it, modifying it, and writing it.
+    pub fn update_volatile_at<U>(&mut self, i: usize, f: U)
+    where
+        T: Copy,
+        U: FnOnce(&mut T),
+    {
+        let mut v = self.read_volatile_at(i);
+        f(&mut v);
+        self.write_volatile_at(i, v);
+    }
+
+    /// Reads up to `out.len()` elements into `out` and writes `T::default()` to each of them, and
+    /// returns the filled part of `out`.
+    pub fn drain_into<'b>(&mut self, out: &'b mut [T]) -> &'b mut [T]
+    where
+        T: Copy + Default,
+    {
+        let n = self.len().min(out.len());
+
+        for (i, slot) in out[..n].iter_mut().enumerate() {
+            *slot = self.read_volatile_at(i);
+            self.write_volatile_at(i, T::default());
+        }
+
+        &mut out[..n]
+    }
+
+    /// Moves the elements for which `pred` returns `true` to the front of the array in order, and
+    /// returns the number of them.
+    ///
+    /// The elements after the moved ones are left untouched.
+    pub fn compact<F>(&mut self, mut pred: F) -> usize
+    where
+        T: Copy,
+        F: FnMut(&T) -> bool,
+    {
+        let mut n = 0;
+
+        for i in 0..self.len() {
+            let v = self.read_volatile_at(i);
+
+            if pred(&v) {
+                if n != i {
+                    self.write_volatile_at(n, v);
+                }
+                n += 1;
+            }
+        }
+
+        n
+    }
+
+    /// Returns a view of the array which allows reading and writing the elements through a shared
+    /// reference, as [`core::cell::Cell::as_slice_of_cells`] does for slices.
+    ///
+    /// This method takes `&mut self` so that no other reference to the accessor exists while the
+    /// view lives. The view is not [`Sync`], so the elements can be accessed only in a single
+    /// thread.
+    pub fn as_cells(&mut self) -> CellArray<'_, T, M> {
+        CellArray {
+            virt: self.virt,
+            len: self.len,
+            _lifetime: PhantomData,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Alias of [`Generic::update_volatile_at`].
+    #[deprecated(since = "0.3.1", note = "use `update_volatile_at`")]
+    pub fn update_at<U>(&mut self, i: usize, f: U)
+    where
+        T: Copy,
+        U: FnOnce(&mut T),
+    {
+        self.update_volatile_at(i, f);
+    }
+}
+impl<T, M, A> fmt::Debug for Generic<T, M, A>
+where
+    T: fmt::Debug + Copy,
+    M: Mapper,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+/// Accessors using [`Identity`] can be cloned because [`Identity::unmap`] does nothing. Only
+/// read-only accessors implement [`Clone`] so that a clone never allows writing to the elements
+/// another accessor owns.
+impl<T> Clone for Generic<T, Identity, marker::ReadOnly> {
+    fn clone(&self) -> Self {
+        // SAFETY: The clone points to the same array in the same region.
+        unsafe { Self::from_raw_parts(self.virt, self.len, self.mapping.clone()) }
+    }
+}
+impl<T, M, A> PartialEq for Generic<T, M, A>
+where
+    T: PartialEq + Copy,
+    M: Mapper,
+    A: Readable,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.into_iter().zip(other).all(|(a, b)| a.eq(&b))
+    }
+}
+impl<T, M, A> Eq for Generic<T, M, A>
+where
+    T: Eq + Copy,
+    M: Mapper,
+    A: Readable,
+{
+}
+impl<T, M, A> Hash for Generic<T, M, A>
+where
+    T: Hash + Copy,
+    M: Mapper,
+    A: Readable,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        for e in self {
+            e.hash(state);
+        }
+    }
+}
+impl<'a, T, M, A> IntoIterator for &'a Generic<T, M, A>
+where
+    T: Copy,
+    M: Mapper,
+    A: Readable,
+{
+    type Item = T;
+    type IntoIter = Iter<'a, T, M, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::new(self)
+    }
+}
+
+/// An iterator over a value of `T`.
+///
+/// The iterator yields the values, so [`Iterator::sum`] works as it does for slices. Like the
+/// standard library, it panics on overflow if overflow checks are enabled. Use
+/// [`Generic::try_sum`] or [`Generic::wrapping_sum`] to handle overflow explicitly.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Iter<'a, T, M, A>
+where
+    T: Copy,
+    M: Mapper,
+    A: Readable,
+{
+    a: &'a Generic<T, M, A>,
+    i: usize,
+}
+impl<'a, T, M, A> Iter<'a, T, M, A>
+where
+    T: Copy,
+    M: Mapper,
+    A: Readable,
+{
+    fn new(a: &'a Generic<T, M, A>) -> Self {
+        Self { a, i: 0 }
+    }
+}
+impl<'a, T, M, A> Iterator for Iter<'a, T, M, A>
+where
+    T: Copy,
+    M: Mapper,
+    A: Readable,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i < self.a.len() {
+            let t = self.a.read_volatile_at(self.i);
+            self.i += 1;
+            Some(t)
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over the read-only accessors to the elements of an array.
+///
+/// This is created by [`Generic::iter_accessors`].
+pub struct AccessorIter<'a, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    a: &'a Generic<T, M, A>,
+    front: usize,
+    back: usize,
+}
+impl<'a, T, M, A> Iterator for AccessorIter<'a, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    type Item = single::Bounded<'a, T, M, marker::ReadOnly>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            // SAFETY: The element is in the array, and the accessor is read-only.
+            let a = unsafe { single::Bounded::new(self.a.addr(self.front)) };
+            self.front += 1;
+            Some(a)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+impl<T, M, A> DoubleEndedIterator for AccessorIter<'_, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            // SAFETY: The element is in the array, and the accessor is read-only.
+            Some(unsafe { single::Bounded::new(self.a.addr(self.back)) })
+        } else {
+            None
+        }
+    }
+}
+impl<T, M, A> ExactSizeIterator for AccessorIter<'_, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+}
+impl<T, M, A> fmt::Debug for AccessorIter<'_, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessorIter")
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator over a value of `T`, which yields an error if the value is not mapped.
+///
+/// This is created by [`Generic::try_iter`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct TryIter<'a, T, M, A>
+where
+    T: Copy,
+    M: Mapper,
+    A: Readable,
+{
+    a: &'a Generic<T, M, A>,
+    i: usize,
+}
+impl<T, M, A> Iterator for TryIter<'_, T, M, A>
+where
+    T: Copy,
+    M: Mapper,
+    A: Readable,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.a.len() {
+            return None;
+        }
+
+        let address = self.a.addr(self.i);
+        if self
+            .a
+            .mapping
+            .mapper()
+            .is_mapped(address, mem::size_of::<T>())
+        {
+            let t = self.a.read_volatile_at(self.i);
+            self.i += 1;
+            Some(Ok(t))
+        } else {
+            self.i = self.a.len();
+            Some(Err(Error::NotMapped { address }))
+        }
+    }
+}
+
+/// A view of an array accessor which allows reading and writing the elements through a shared
+/// reference.
+///
+/// This is created by [`Generic::as_cells`].
+pub struct CellArray<'a, T, M>
+where
+    M: Mapper,
+{
+    virt: usize,
+    len: usize,
+    _lifetime: PhantomData<&'a mut M>,
+    // `core::cell::Cell` makes this type `!Sync`.
+    _marker: PhantomData<core::cell::Cell<T>>,
+}
+#[allow(clippy::len_without_is_empty)] // Array is never empty.
+impl<T, M> CellArray<'_, T, M>
+where
+    M: Mapper,
+{
+    /// Returns the length of the array.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Reads the `i`th element.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `i >= self.len()`.
+    #[must_use]
+    pub fn get(&self, i: usize) -> T
+    where
+        T: Copy,
+    {
+        // SAFETY: The address is aligned properly as `Generic::as_cells` ensures.
+        unsafe { volatile::read(self.addr(i)) }
+    }
+
+    /// Writes `v` as the `i`th element.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `i >= self.len()`.
+    pub fn set(&self, i: usize, v: T) {
+        // SAFETY: The address is aligned properly as `Generic::as_cells` ensures, and this type is
+        // not `Sync`, so no other thread accesses the element.
+        unsafe { volatile::write(self.addr(i), v) }
+    }
+
+    fn addr(&self, i: usize) -> usize {
+        assert!(i < self.len());
+
+        self.virt + mem::size_of::<T>() * i
+    }
+}
+impl<T, M> fmt::Debug for CellArray<'_, T, M>
+where
+    T: fmt::Debug + Copy,
+    M: Mapper,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.len()).map(|i| self.get(i)))
+            .finish()
+    }
+}
+
+/// A view of a part of an array accessor.
+///
+/// Unlike [`Generic`], this view does not own a mapping. It borrows the mapping of the accessor it
+/// is derived from, so it never calls [`Mapper::unmap`] and cannot outlive the accessor.
+pub struct SubArray<'a, T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    virt: usize,
+    len: usize,
+    _lifetime: PhantomData<&'a M>,
+    _marker: PhantomData<T>,
+    _read_write: PhantomData<A>,
+}
+#[allow(clippy::len_without_is_empty)] // `is_empty` is not worth adding for a view.
+impl<T, M, A> SubArray<'_, T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    /// Creates a view of `[T; len]` at the virtual address `virt`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `[T; len]` at `virt` is valid and mapped while the returned
+    /// view lives, that `virt` is aligned properly, and that no other accessor accesses the
+    /// elements in a way that the type specifier `A` does not allow.
+    pub(crate) unsafe fn new(virt: usize, len: usize) -> Self {
+        Self {
+            virt,
+            len,
+            _lifetime: PhantomData,
+            _marker: PhantomData,
+            _read_write: PhantomData,
+        }
+    }
+
+    /// Returns the number of the elements in the view.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    fn addr(&self, i: usize) -> usize {
+        assert!(i < self.len());
+
+        self.virt + mem::size_of::<T>() * i
+    }
+}
+impl<T, M, A> SubArray<'_, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    /// Reads the `i`th element of the view.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `i >= self.len()`.
+    #[must_use]
+    pub fn read_volatile_at(&self, i: usize) -> T
+    where
+        T: Copy,
+    {
+        // SAFETY: `SubArray::new` ensures that the address is aligned properly.
+        unsafe { volatile::read(self.addr(i)) }
+    }
+}
+impl<T, M, A> SubArray<'_, T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    /// Writes `v` as the `i`th element of the view.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `i >= self.len()`.
+    pub fn write_volatile_at(&mut self, i: usize, v: T) {
+        // SAFETY: `SubArray::new` ensures that the address is aligned properly.
+        unsafe { volatile::write(self.addr(i), v) }
+    }
+}
+impl<T, M, A> fmt::Debug for SubArray<'_, T, M, A>
+where
+    T: fmt::Debug + Copy,
+    M: Mapper,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.len()).map(|i| self.read_volatile_at(i)))
+            .finish()
+    }
+}
+
+/// A view which regards an array accessor as a two-dimensional grid.
+///
+/// This is created by [`Generic::as_grid`] or [`Generic::as_grid_mut`].
+pub struct Grid<'a, T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    elements: SubArray<'a, T, M, A>,
+    cols: usize,
+}
+impl<'a, T, M, A> Grid<'a, T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    fn new(elements: SubArray<'a, T, M, A>, cols: usize) -> Self {
+        Self { elements, cols }
+    }
+
+    /// Returns the number of the rows.
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.elements.len() / self.cols
+    }
+
+    /// Returns the number of the columns.
+    #[must_use]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn index(&self, r: usize, c: usize) -> usize {
+        assert!(r < self.rows());
+        assert!(c < self.cols());
+
+        r * self.cols + c
+    }
+}
+impl<T, M, A> Grid<'_, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    /// Reads the element at the row `r` and the column `c`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `r >= self.rows()` or `c >= self.cols()`.
+    #[must_use]
+    pub fn get(&self, r: usize, c: usize) -> T
+    where
+        T: Copy,
+    {
+        self.elements.read_volatile_at(self.index(r, c))
+    }
+}
+impl<T, M, A> Grid<'_, T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    /// Writes `v` as the element at the row `r` and the column `c`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `r >= self.rows()` or `c >= self.cols()`.
+    #[allow(clippy::too_many_arguments)] // Both coordinates are needed.
+    pub fn set(&mut self, r: usize, c: usize, v: T) {
+        let i = self.index(r, c);
+        self.elements.write_volatile_at(i, v);
+    }
+}
+impl<T, M, A> fmt::Debug for Grid<'_, T, M, A>
+where
+    T: fmt::Debug + Copy,
+    M: Mapper,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Grid")
+            .field("rows", &self.rows())
+            .field("cols", &self.cols())
+            .field("elements", &self.elements)
+            .finish()
+    }
+}
+
+/// Returns the number of bytes an accessor to `[T; len]` maps, or `None` if it overflows.
+///
+/// This is useful to prepare the page tables before creating an accessor.
+///
+/// # Examples
+///
+/// ```
+/// use accessor::array;
+///
+/// assert_eq!(array::required_bytes::<u32>(3), Some(12));
+/// ```
+#[must_use]
+pub fn required_bytes<T>(len: usize) -> Option<usize> {
+    mem::size_of::<T>().checked_mul(len)
+}
+
+// TODO: Rewrite the following tests as doc tests once
+// https://github.com/rust-lang/rust/issues/78695 is fixed.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::volatile::Register;
+    use core::{cell::Cell, num::NonZeroUsize};
+
+    struct M;
+    impl Mapper for M {
+        unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+            NonZeroUsize::new(phys_start).unwrap()
+        }
+
+        fn unmap(&mut self, _: usize, _: usize) {}
+    }
+
+    #[test]
+    fn test_read_volatile_at() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.read_volatile_at(0), 1);
+    }
+
+    #[test]
+    fn test_write_volatile_at() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.write_volatile_at(0, 42);
+        assert_eq!(arr[0], 42);
+    }
+
+    #[test]
+    fn test_update_volatile_at() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.update_volatile_at(0, |v| {
+            *v *= 2;
+        });
+        assert_eq!(arr[0], 2);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let mut iter = a.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_eq() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+        let b = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_bounds() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let _ = a.read_volatile_at(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_array() {
+        let arr = [1, 2, 3, 4, 5];
+        let _ = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), 0, M) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_not_aligned() {
+        let arr = [1, 2, 3, 4, 5];
+        let _ = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr) + 1, arr.len(), M) };
+    }
+
+    #[test]
+    fn test_err_empty_array() {
+        let arr = [1, 2, 3, 4, 5];
+        let r = unsafe { ReadOnly::<u32, _>::try_new(base_addr(&arr), 0, M) };
+
+        assert_eq!(r, Err(Error::EmptyArray));
+    }
+
+    #[test]
+    fn test_err_not_aligned() {
+        let arr = [1, 2, 3, 4, 5];
+        let r = unsafe { ReadOnly::<u32, _>::try_new(base_addr(&arr) + 1, arr.len(), M) };
+
+        assert_eq!(
+            r,
+            Err(Error::NotAligned {
+                alignment: mem::align_of::<u32>(),
+                address: base_addr(&arr) + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_into_read_only() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadWrite::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+        let a = a.into_read_only();
+
+        assert_eq!(a.read_volatile_at(2), 3);
+        assert_eq!(a.len(), 5);
+    }
+
+    #[test]
+    fn test_checked_at() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.checked_at(3).map(|e| e.read_volatile()), Ok(4));
+    }
+
+    #[test]
+    fn test_err_checked_at_out_of_bounds() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(
+            a.checked_at(5).map(|e| e.read_volatile()),
+            Err(Error::OutOfBounds { index: 5, len: 5 })
+        );
+    }
+
+    #[test]
+    fn test_as_chunks() {
+        let arr = [1, 2, 3, 4, 5, 6];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+        let a = a.as_chunks::<2>();
+
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.read_volatile_at(1), [3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_as_chunks_not_multiple() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let _ = a.as_chunks::<2>();
+    }
+
+    #[test]
+    fn test_new_aligned_up() {
+        let arr = [1, 2, 3, 4, 5];
+        let (a, padding) = unsafe { ReadOnly::<u32, _>::new_aligned_up(base_addr(&arr) + 1, 4, M) };
+
+        assert_eq!(padding, mem::align_of::<u32>() - 1);
+        assert_eq!(a.read_volatile_at(0), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_aligned_up_overflow() {
+        let _ = unsafe { ReadOnly::<u32, _>::new_aligned_up(usize::MAX, 1, M) };
+    }
+
+    #[test]
+    fn test_try_for_each() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let mut visited = 0;
+        let r = a.try_for_each(|v| {
+            visited += 1;
+
+            if v == 3 {
+                Err(v)
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(r, Err(3));
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let arr = [1, 2, 3, 10, 11];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.partition_point(|x| *x < 10), 3);
+        assert_eq!(a.partition_point(|x| *x < 100), 5);
+        assert_eq!(a.partition_point(|_| false), 0);
+    }
+
+    #[test]
+    fn test_for_indices_mut() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.for_indices_mut([0, 2, 4], |i, e| e.write_volatile(i as u32 * 10));
+        assert_eq!(arr, [0, 2, 20, 4, 40]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_for_indices_mut_out_of_bounds() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.for_indices_mut([0, 5], |_, e| e.write_volatile(0));
+    }
+
+    #[test]
+    fn test_fill_range() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.fill_range(1..4, 0);
+        assert_eq!(arr, [1, 0, 0, 0, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fill_range_out_of_bounds() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.fill_range(3..6, 0);
+    }
+
+    #[test]
+    fn test_scan_into() {
+        let arr = [1, 2, 3, 4];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let mut out = [0; 6];
+        let n = a.scan_into(0, &mut out, |sum, v| {
+            *sum += v;
+            *sum
+        });
+
+        assert_eq!(n, 4);
+        assert_eq!(out, [1, 3, 6, 10, 0, 0]);
+
+        let mut out = [0; 2];
+        assert_eq!(a.scan_into(0, &mut out, |_, v| v), 2);
+        assert_eq!(out, [1, 2]);
+    }
+
+    #[test]
+    fn test_read_clone_at() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct W(u32);
+
+        let arr = [W(1), W(2), W(3)];
+        let a = unsafe { ReadOnly::<W, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.read_clone_at(1), W(2));
+    }
+
+    #[test]
+    fn test_fill_step() {
+        let mut arr = [1, 2, 3, 4, 5, 6];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.fill_step(1, 2, 0);
+        assert_eq!(arr, [1, 0, 3, 0, 5, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fill_step_zero() {
+        let mut arr = [1, 2, 3, 4, 5, 6];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.fill_step(1, 0, 0);
+    }
+
+    #[test]
+    fn test_fill_cyclic() {
+        let mut arr = [0; 5];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.fill_cyclic(&[1, 2]);
+        assert_eq!(arr, [1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fill_cyclic_empty() {
+        let mut arr = [0; 5];
+        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.fill_cyclic(&[]);
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let mut iter = a.iter_from(3);
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(a.iter_from(5).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iter_from_out_of_bounds() {
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let _ = a.iter_from(6);
+    }
+
+    #[test]
+    fn test_leak() {
+        struct Tracking<'a> {
+            unmapped: &'a Cell<bool>,
+        }
+        impl Mapper for Tracking<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, _: usize, _: usize) {
+                self.unmapped.set(true);
+            }
+        }
+
+        let unmapped = Cell::new(false);
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe {
+            ReadOnly::<u32, _>::new(
+                base_addr(&arr),
+                arr.len(),
+                Tracking {
+                    unmapped: &unmapped,
+                },
+            )
+        };
+
+        assert_eq!(a.leak(), base_addr(&arr));
+        assert!(!unmapped.get());
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let mut arr = [1, 2, 3, 4, 5];
+        let mut a = unsafe { ReadWrite::<u32, _>::from_slice(&mut arr) };
+
+        assert_eq!(a.len(), 5);
+        assert_eq!(a.read_volatile_at(1), 2);
+
+        a.write_volatile_at(3, 42);
+        assert_eq!(arr[3], 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_slice_empty() {
+        let _ = unsafe { ReadWrite::<u32, _>::from_slice(&mut []) };
+    }
+
+    #[test]
+    fn test_count() {
+        let arr = [1, 2, 3, 4, 5, 6];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.count(|v| v % 2 == 0), 3);
+    }
+
+    #[test]
+    fn test_from_non_null() {
+        let arr = [1, 2, 3, 4, 5];
+        let ptr = NonNull::from(&arr).cast::<u32>();
+        let a = unsafe { ReadOnly::<u32, _>::from_non_null(ptr, arr.len(), Identity) };
+
+        assert_eq!(a.read_volatile_at(4), 5);
+    }
+
+    #[test]
+    fn test_max_len() {
+        assert_eq!(ReadOnly::<u32, M>::MAX_LEN, usize::MAX / 4);
+        assert_eq!(ReadOnly::<u8, M>::MAX_LEN, usize::MAX);
+    }
+
+    #[test]
+    fn test_err_size_overflow() {
+        let arr = [1, 2, 3, 4, 5];
+        let len = ReadOnly::<u32, M>::MAX_LEN + 1;
+        let r = unsafe { ReadOnly::<u32, _>::try_new(base_addr(&arr), len, M) };
+
+        assert_eq!(r, Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_size_overflow() {
+        let arr = [1, 2, 3, 4, 5];
+        let len = ReadOnly::<u32, M>::MAX_LEN + 1;
+        let _ = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), len, M) };
+    }
+
+    #[test]
+    fn test_into_single_at() {
+        struct Tracking<'a> {
+            unmapped: &'a Cell<Option<(usize, usize)>>,
+        }
+        impl Mapper for Tracking<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, virt_start: usize, bytes: usize) {
+                self.unmapped.set(Some((virt_start, bytes)));
+            }
+        }
+
+        let unmapped = Cell::new(None);
+        let arr = [1, 2, 3, 4, 5];
+        let a = unsafe {
+            ReadOnly::<u32, _>::new(
+                base_addr(&arr),
+                arr.len(),
+                Tracking {
+                    unmapped: &unmapped,
+                },
+            )
+        };
+        let s = a.into_single_at(2);
+
+        assert_eq!(s.read_volatile(), 3);
+        assert_eq!(unmapped.get(), None);
+
+        drop(s);
+        assert_eq!(unmapped.get(), Some((base_addr(&arr), 20)));
+    }
+
+    #[test]
+    fn test_unmap_bytes_match_map_bytes() {
+        struct Tracking<'a> {
+            mapped: &'a Cell<usize>,
+            unmapped: &'a Cell<usize>,
+        }
+        impl Mapper for Tracking<'_> {
+            unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize {
+                self.mapped.set(bytes);
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, _: usize, bytes: usize) {
+                self.unmapped.set(bytes);
+            }
+        }
+
+        let mapped = Cell::new(0);
+        let unmapped = Cell::new(0);
+        let arr = [0_u32; 6];
+        let a = unsafe {
+            ReadOnly::<u32, _>::new(
+                base_addr(&arr),
+                arr.len(),
+                Tracking {
+                    mapped: &mapped,
+                    unmapped: &unmapped,
+                },
+            )
+        };
+
+        drop(a.as_chunks::<2>().into_single_at(1));
+
+        assert_eq!(mapped.get(), 24);
+        assert_eq!(unmapped.get(), mapped.get());
+    }
+
+    #[test]
+    fn test_iter_pairs() {
+        let arr = [1, 2, 3, 4];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let mut it = a.iter_pairs();
+        assert_eq!(it.next(), Some((1, 2)));
+        assert_eq!(it.next(), Some((3, 4)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iter_pairs_odd_len() {
+        let arr = [1, 2, 3];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let _ = a.iter_pairs();
+    }
+
+    #[test]
+    fn test_copy_from_accessor() {
+        let src = [1, 2, 3];
+        let mut dst = [0; 5];
+
+        let s = unsafe { ReadOnly::<u32, _>::new(base_addr(&src), src.len(), M) };
+        let mut d = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut dst), dst.len(), M) };
+
+        assert_eq!(d.copy_from_accessor(&s), 3);
+        assert_eq!(dst, [1, 2, 3, 0, 0]);
+    }
+
+    #[test]
+    fn test_try_new_within() {
+        let arr = [0_u32; 4];
+        let base = base_addr(&arr);
+        let end = base + 16;
+
+        let a = unsafe { ReadOnly::<u32, _>::try_new_within(base, 4, end, M) };
+        assert!(a.is_ok());
+
+        let a = unsafe { ReadOnly::<u32, _>::try_new_within(base, 4, end - 1, M) };
+        assert_eq!(
+            a.unwrap_err(),
+            Error::OutOfRegion {
+                end,
+                max_end: end - 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_map() {
+        let arr = [1, 5, 2, 7];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.find_map(|x| (x > 3).then(|| x * 2)), Some(10));
+        assert_eq!(a.find_map(|x| (x > 7).then(|| x * 2)), None);
+    }
+
+    #[test]
+    fn test_try_read_all() {
+        let arr = [1, 2, 3];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.try_read_all::<3>(), Ok([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_try_read_all_len_mismatch() {
+        let arr = [1, 2, 3];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(
+            a.try_read_all::<4>(),
+            Err(Error::LengthMismatch {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_collect_into() {
+        let arr = [1, 2, 3];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let mut buf = [mem::MaybeUninit::<u32>::uninit(); 8];
+        let collected = a.collect_into(&mut buf);
+
+        assert_eq!(collected.len(), 3);
+        assert_eq!(collected, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_as_cells() {
+        fn set_through_shared(c: &CellArray<'_, u32, M>, i: usize, v: u32) {
+            c.set(i, v);
         }
+
+        let mut arr = [0; 3];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        let c = a.as_cells();
+        set_through_shared(&c, 0, 1);
+        set_through_shared(&c, 2, 3);
+
+        assert_eq!(c.get(0), 1);
+        assert_eq!(c.get(1), 0);
+        assert_eq!(c.get(2), 3);
     }
-}
-impl<'a, T, M, A> IntoIterator for &'a Generic<T, M, A>
-where
-    M: Mapper,
-    A: Readable,
-{
-    type Item = T;
-    type IntoIter = Iter<'a, T, M, A>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        Iter::new(self)
+    #[test]
+    fn test_required_bytes() {
+        assert_eq!(required_bytes::<u32>(3), Some(12));
+        assert_eq!(required_bytes::<u64>(usize::MAX), None);
     }
-}
-impl<T, M, A> Drop for Generic<T, M, A>
-where
-    M: Mapper,
-    A: AccessorTypeSpecifier,
-{
-    fn drop(&mut self) {
-        let bytes = mem::size_of::<T>() * self.len;
-        self.mapper.unmap(self.virt, bytes);
+
+    #[test]
+    fn test_is_sorted() {
+        let arr = [1, 2, 2, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert!(a.is_sorted());
     }
-}
 
-/// An iterator over a value of `T`.
-#[derive(Debug, PartialEq, Eq, Hash)]
-pub struct Iter<'a, T, M, A>
-where
-    M: Mapper,
-    A: Readable,
-{
-    a: &'a Generic<T, M, A>,
-    i: usize,
-}
-impl<'a, T, M, A> Iter<'a, T, M, A>
-where
-    M: Mapper,
-    A: Readable,
-{
-    fn new(a: &'a Generic<T, M, A>) -> Self {
-        Self { a, i: 0 }
+    #[test]
+    fn test_is_sorted_unsorted() {
+        let arr = [1, 3, 2, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert!(!a.is_sorted());
     }
-}
-impl<'a, T, M, A> Iterator for Iter<'a, T, M, A>
-where
-    M: Mapper,
-    A: Readable,
-{
-    type Item = T;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.i < self.a.len() {
-            let t = self.a.read_volatile_at(self.i);
-            self.i += 1;
-            Some(t)
-        } else {
-            None
+    #[test]
+    fn test_try_iter() {
+        struct Partial {
+            mapped_end: usize,
         }
-    }
-}
+        impl Mapper for Partial {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
 
-// TODO: Rewrite the following tests as doc tests once
-// https://github.com/rust-lang/rust/issues/78695 is fixed.
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::num::NonZeroUsize;
+            fn unmap(&mut self, _: usize, _: usize) {}
 
-    struct M;
-    impl Mapper for M {
-        unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
-            NonZeroUsize::new(phys_start).unwrap()
+            fn is_mapped(&self, virt_start: usize, bytes: usize) -> bool {
+                virt_start + bytes <= self.mapped_end
+            }
         }
 
-        fn unmap(&mut self, _: usize, _: usize) {}
+        let arr = [1, 2, 3, 4];
+        let mapped_end = base_addr(&arr) + 8;
+        let a =
+            unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), Partial { mapped_end }) };
+
+        let mut it = a.try_iter();
+        assert_eq!(it.next(), Some(Ok(1)));
+        assert_eq!(it.next(), Some(Ok(2)));
+        assert_eq!(
+            it.next(),
+            Some(Err(Error::NotMapped {
+                address: mapped_end
+            }))
+        );
+        assert_eq!(it.next(), None);
     }
 
     #[test]
-    fn test_read_volatile_at() {
-        let arr = [1, 2, 3, 4, 5];
+    fn test_compact() {
+        let mut arr = [1, 0, 2, 0, 3];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        assert_eq!(a.compact(|x| *x != 0), 3);
+        assert_eq!(arr[..3], [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_get_disjoint_ranges_mut() {
+        let mut arr = [0; 5];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        let (mut x, mut y) = a.get_disjoint_ranges_mut(0..2, 3..5).unwrap();
+        assert_eq!((x.len(), y.len()), (2, 2));
+
+        x.write_volatile_at(1, 1);
+        y.write_volatile_at(0, 2);
+        assert_eq!(x.read_volatile_at(1), 1);
+        assert_eq!(y.read_volatile_at(0), 2);
+
+        assert_eq!(arr, [0, 1, 0, 2, 0]);
+    }
+
+    #[test]
+    fn test_get_disjoint_ranges_mut_errors() {
+        let mut arr = [0; 5];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        assert_eq!(
+            a.get_disjoint_ranges_mut(0..3, 2..5).unwrap_err(),
+            Error::OverlappingIndices
+        );
+        assert_eq!(
+            a.get_disjoint_ranges_mut(0..2, 3..6).unwrap_err(),
+            Error::OutOfBounds { index: 5, len: 5 }
+        );
+    }
+
+    #[test]
+    fn test_virt() {
+        let arr = [0_u32; 3];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), Identity) };
+
+        assert_eq!(a.virt(), base_addr(&arr));
+    }
+
+    #[test]
+    fn test_write_all() {
+        let mut arr = [0; 3];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.write_all([10, 20, 30]);
+        assert_eq!(arr, [10, 20, 30]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_write_all_len_mismatch() {
+        let mut arr = [0; 3];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.write_all([10, 20]);
+    }
+
+    #[test]
+    fn test_wrapping_sum() {
+        let arr = [200_u8, 100, 10];
+        let a = unsafe { ReadOnly::<u8, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.wrapping_sum(), 54);
+        assert_eq!(a.checked_sum(), None);
+        assert_eq!(a.wrapping_product(), 64);
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        let arr = [1_u8, 2, 3];
+        let a = unsafe { ReadOnly::<u8, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.checked_sum(), Some(6));
+        assert_eq!(a.wrapping_product(), 6);
+    }
+
+    #[test]
+    fn test_iter_sum() {
+        let arr = [1_u32, 2, 3, 4];
         let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
 
-        assert_eq!(a.read_volatile_at(0), 1);
+        assert_eq!(a.into_iter().sum::<u32>(), 10);
+        assert_eq!(a.into_iter().sum::<u32>(), a.checked_sum().unwrap());
     }
 
     #[test]
-    fn test_write_volatile_at() {
-        let mut arr = [1, 2, 3, 4, 5];
-        let mut a = unsafe { WriteOnly::<u32, _>::new(base_addr(&mut arr), arr.len(), M) };
+    fn test_try_sum() {
+        let arr = [100_u8, 100, 55];
+        let a = unsafe { ReadOnly::<u8, _>::new(base_addr(&arr), arr.len(), M) };
+        assert_eq!(a.try_sum(), Some(255));
 
-        a.write_volatile_at(0, 42);
-        assert_eq!(arr[0], 42);
+        let arr = [100_u8, 100, 56];
+        let a = unsafe { ReadOnly::<u8, _>::new(base_addr(&arr), arr.len(), M) };
+        assert_eq!(a.try_sum(), None);
     }
 
     #[test]
-    fn test_update_volatile_at() {
-        let mut arr = [1, 2, 3, 4, 5];
-        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr(&mut arr), arr.len(), M) };
+    fn test_clone_identity() {
+        let arr = [1, 2, 3, 4];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), Identity) };
+        let b = a.clone();
 
-        a.update_volatile_at(0, |v| {
-            *v *= 2;
-        });
-        assert_eq!(arr[0], 2);
+        assert_eq!(b.len(), 4);
+        assert!(a.into_iter().eq(b.into_iter()));
+        assert!(b.into_iter().eq(arr.iter().copied()));
     }
 
     #[test]
-    fn test_into_iter() {
-        let arr = [1, 2, 3, 4, 5];
+    fn test_drain_into() {
+        let mut arr = [1, 2, 3];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        let mut out = [0; 2];
+        assert_eq!(a.drain_into(&mut out), [1, 2]);
+        assert_eq!(arr, [0, 0, 3]);
+    }
+
+    #[test]
+    fn test_try_new_from_virt() {
+        let arr = [1_u32, 2, 3];
+        let virt = base_addr(&arr);
+
+        let a = unsafe { ReadOnly::<u32, _>::try_new_from_virt(virt, arr.len(), Identity) };
+        assert_eq!(a.map(|a| a.read_volatile_at(2)), Ok(3));
+
+        let a = unsafe { ReadOnly::<u32, _>::try_new_from_virt(virt, 0, Identity) };
+        assert_eq!(a.unwrap_err(), Error::EmptyArray);
+
+        let a = unsafe { ReadOnly::<u32, _>::try_new_from_virt(virt, usize::MAX / 4, Identity) };
+        assert_eq!(a.unwrap_err(), Error::SizeOverflow);
+
+        let a = unsafe { ReadOnly::<u32, _>::try_new_from_virt(virt + 1, arr.len(), Identity) };
+        assert_eq!(
+            a.unwrap_err(),
+            Error::NotAligned {
+                address: virt + 1,
+                alignment: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_filter_collect_into() {
+        let arr = [1, 2, 3, 4];
         let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
 
-        let mut iter = a.into_iter();
-        assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.next(), Some(2));
-        assert_eq!(iter.next(), Some(3));
-        assert_eq!(iter.next(), Some(4));
-        assert_eq!(iter.next(), Some(5));
-        assert_eq!(iter.next(), None);
+        let mut out = [0; 4];
+        assert_eq!(a.filter_collect_into(|x| x % 2 == 0, &mut out), 2);
+        assert_eq!(out[..2], [2, 4]);
+
+        let mut out = [0; 1];
+        assert_eq!(a.filter_collect_into(|x| x % 2 == 0, &mut out), 1);
+        assert_eq!(out, [2]);
     }
 
     #[test]
-    fn test_eq() {
-        let arr = [1, 2, 3, 4, 5];
+    fn test_filter_collect_into_stops_reading() {
+        let reg = Register::array(&[1, 2, 3, 4]);
+        let a = unsafe { ReadOnly::<u32, _>::new(reg.addr(), 4, M) };
+
+        let mut out = [0; 1];
+        assert_eq!(a.filter_collect_into(|x| x % 2 == 0, &mut out), 1);
+        assert_eq!(reg.reads(), 2);
+
+        assert_eq!(a.filter_collect_into(|x| x % 2 == 0, &mut []), 0);
+        assert_eq!(reg.reads(), 2);
+    }
+
+    #[test]
+    fn test_new_with_max_bytes() {
+        let arr = [1_u32, 2, 3];
+
+        let a = unsafe { ReadOnly::<u32, _>::new_with_max_bytes(base_addr(&arr), 3, 12, M) };
+        assert_eq!(a.map(|a| a.len()), Ok(3));
+
+        let a = unsafe { ReadOnly::<u32, _>::new_with_max_bytes(base_addr(&arr), 4, 12, M) };
+        assert_eq!(
+            a.unwrap_err(),
+            Error::TooLarge {
+                requested: 16,
+                max: 12
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_into_ref() {
+        let arr = [1, 2, 3, 4, 5, 6];
         let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
-        let b = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
 
-        assert_eq!(a, b);
+        let [x, y, z] = a.split_into_ref::<3>();
+        assert_eq!((x.len(), y.len(), z.len()), (2, 2, 2));
+        assert_eq!(x.read_volatile_at(0), 1);
+        assert_eq!(y.read_volatile_at(0), 3);
+        assert_eq!(z.read_volatile_at(1), 6);
+
+        let [x, y] = a.split_into_ref::<2>();
+        assert_eq!((x.len(), y.len()), (3, 3));
+
+        let [x, y, z, w] = a.split_into_ref::<4>();
+        assert_eq!((x.len(), y.len(), z.len(), w.len()), (2, 2, 1, 1));
+        assert_eq!(w.read_volatile_at(0), 6);
     }
 
     #[test]
-    #[should_panic]
-    fn test_out_of_bounds() {
+    fn test_iter_rev_from() {
         let arr = [1, 2, 3, 4, 5];
         let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
 
-        let _ = a.read_volatile_at(5);
+        assert!(a.iter_rev_from(3).eq([3, 2, 1].iter().copied()));
+        assert_eq!(a.iter_rev_from(0).next(), None);
     }
 
     #[test]
-    #[should_panic]
-    fn test_empty_array() {
-        let arr = [1, 2, 3, 4, 5];
-        let _ = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), 0, M) };
+    fn test_as_grid() {
+        let arr = [1, 2, 3, 4, 5, 6];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let g = a.as_grid(2, 3);
+        assert_eq!((g.rows(), g.cols()), (2, 3));
+        assert_eq!(g.get(1, 2), 6);
+        assert_eq!(g.get(0, 1), 2);
+    }
+
+    #[test]
+    fn test_as_grid_mut() {
+        let mut arr = [0; 6];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        a.as_grid_mut(2, 3).set(1, 0, 42);
+        assert_eq!(arr, [0, 0, 0, 42, 0, 0]);
     }
 
     #[test]
     #[should_panic]
-    fn test_not_aligned() {
+    fn test_as_grid_out_of_bounds() {
+        let arr = [1, 2, 3, 4, 5, 6];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let _ = a.as_grid(2, 3).get(0, 3);
+    }
+
+    #[test]
+    fn test_try_new_zero_sized() {
+        let arr = [(); 3];
+        let a = unsafe { ReadOnly::<(), _>::try_new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.unwrap_err(), Error::ZeroSized);
+    }
+
+    #[test]
+    fn test_iter_accessors_rev() {
+        let arr = [1, 2, 3];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let mut it = a.iter_accessors();
+        assert_eq!(it.len(), 3);
+
+        assert_eq!(it.next_back().map(|e| e.read_volatile()), Some(3));
+        assert_eq!(it.len(), 2);
+        assert!(it
+            .rev()
+            .map(|e| e.read_volatile())
+            .eq([2, 1].iter().copied()));
+    }
+
+    #[test]
+    fn test_try_concat() {
+        let arr = [1, 2, 3, 4];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), 2, Identity) };
+        let b = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr[2..]), 2, Identity) };
+
+        let c = a.try_concat(&b).unwrap();
+        assert_eq!(c.len(), 4);
+        assert_eq!(c.read_volatile_at(0), 1);
+        assert_eq!(c.read_volatile_at(3), 4);
+
+        assert!(b.try_concat(&a).is_none());
+    }
+
+    #[test]
+    fn test_try_subslice() {
         let arr = [1, 2, 3, 4, 5];
-        let _ = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr) + 1, arr.len(), M) };
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        let s = a.try_subslice(1..4).unwrap();
+        assert_eq!(s.len(), 3);
+        assert_eq!(s.read_volatile_at(0), 2);
+        assert_eq!(s.read_volatile_at(2), 4);
     }
 
     #[test]
-    fn test_err_empty_array() {
+    fn test_try_subslice_out_of_bounds() {
         let arr = [1, 2, 3, 4, 5];
-        let r = unsafe { ReadOnly::<u32, _>::try_new(base_addr(&arr), 0, M) };
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
 
-        assert_eq!(r, Err(Error::EmptyArray));
+        assert_eq!(
+            a.try_subslice(3..6).unwrap_err(),
+            Error::OutOfBounds { index: 5, len: 5 }
+        );
     }
 
     #[test]
-    fn test_err_not_aligned() {
+    fn test_try_subslice_empty() {
         let arr = [1, 2, 3, 4, 5];
-        let r = unsafe { ReadOnly::<u32, _>::try_new(base_addr(&arr) + 1, arr.len(), M) };
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.try_subslice(2..2).unwrap_err(), Error::EmptyRange);
+    }
+
+    #[test]
+    fn test_new_zeroed() {
+        let mut arr = [1_u32, 2, 3, 4, 5];
+        let a = unsafe { ReadOnly::<u32, _>::new_zeroed(base_addr_mut(&mut arr), arr.len(), M) };
+
+        assert!(a.into_iter().all(|v| v == 0));
+
+        drop(a);
+        assert_eq!(arr, [0; 5]);
+    }
+
+    #[test]
+    fn test_new_zeroed_struct() {
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        #[repr(C)]
+        struct Descriptor {
+            addr: u64,
+            len: u32,
+            flags: u16,
+        }
+        // SAFETY: All fields are integers.
+        unsafe impl Zeroable for Descriptor {}
+
+        let d = Descriptor {
+            addr: 0x1000,
+            len: 4,
+            flags: 1,
+        };
+        let mut arr = [d; 3];
+        let a =
+            unsafe { ReadOnly::<Descriptor, _>::new_zeroed(base_addr_mut(&mut arr), arr.len(), M) };
+
+        let zero = Descriptor {
+            addr: 0,
+            len: 0,
+            flags: 0,
+        };
+        assert!(a.into_iter().all(|v| v == zero));
+    }
+
+    #[test]
+    fn test_value_at() {
+        let arr = [1_u32, 2, 3];
+        let a = unsafe { ReadOnly::<u32, _>::new(base_addr(&arr), arr.len(), M) };
+
+        for i in 0..arr.len() {
+            assert_eq!(a.value_at(i), a.read_volatile_at(i));
+        }
+    }
 
+    #[test]
+    fn test_binary_search_by_key() {
+        let arr = [(1_u32, 10_u32), (3, 30), (5, 50), (7, 70)];
+        let a = unsafe { ReadOnly::<(u32, u32), _>::new(base_addr(&arr), arr.len(), M) };
+
+        assert_eq!(a.binary_search_by_key(&5, |&(id, _)| id), Ok(2));
+        assert_eq!(a.binary_search_by_key(&1, |&(id, _)| id), Ok(0));
+        assert_eq!(a.binary_search_by_key(&4, |&(id, _)| id), Err(2));
+        assert_eq!(a.binary_search_by_key(&8, |&(id, _)| id), Err(4));
+        assert_eq!(a.binary_search_by_key(&0, |&(id, _)| id), Err(0));
+    }
+
+    #[test]
+    fn test_as_ptr_range() {
+        let mut arr = [1_u32, 2, 3, 4, 5];
+        let mut a = unsafe { ReadWrite::<u32, _>::new(base_addr_mut(&mut arr), arr.len(), M) };
+
+        let r = a.as_ptr_range();
+        assert_eq!(r.start as usize, a.virt());
         assert_eq!(
-            r,
-            Err(Error::NotAligned {
-                alignment: mem::align_of::<u32>(),
-                address: base_addr(&arr) + 1,
-            })
+            (r.end as usize - r.start as usize) / mem::size_of::<u32>(),
+            a.len()
+        );
+
+        let r = a.as_mut_ptr_range();
+        assert_eq!(
+            (r.end as usize - r.start as usize) / mem::size_of::<u32>(),
+            a.len()
         );
     }
 
     fn base_addr<T>(a: &[T]) -> usize {
-        return a.as_ptr() as usize;
+        a.as_ptr() as usize
+    }
+
+    fn base_addr_mut<T>(a: &mut [T]) -> usize {
+        a.as_mut_ptr() as usize
     }
 }
@@ -4,6 +4,7 @@ use core::fmt;
 
 /// An enum representing errors.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// The address passed as an argument is not aligned correctly.
     ///
@@ -26,14 +27,87 @@ pub enum Error {
     },
     /// Attempted to create an empty array accessor.
     EmptyArray,
+    /// Computing the address or the size of the memory region overflowed.
+    SizeOverflow,
+    /// The index passed as an argument is out of the bounds of the array.
+    OutOfBounds {
+        /// The index passed as an argument.
+        index: usize,
+        /// The length of the array.
+        len: usize,
+    },
+    /// The condition was not satisfied within the given number of attempts.
+    Timeout,
+    /// The memory region exceeds the end address allowed.
+    OutOfRegion {
+        /// The end address of the memory region (exclusive).
+        end: usize,
+        /// The maximum end address allowed (exclusive).
+        max_end: usize,
+    },
+    /// The length of the array is not the expected one.
+    LengthMismatch {
+        /// The expected length.
+        expected: usize,
+        /// The actual length of the array.
+        got: usize,
+    },
+    /// The range passed as an argument is empty.
+    EmptyRange,
+    /// The ranges or the indices passed as arguments overlap.
+    OverlappingIndices,
+    /// Attempted to create an accessor to a zero-sized type.
+    ZeroSized,
+    /// The value read to probe a device was not the expected one.
+    ProbeFailed,
+    /// The memory region is larger than the maximum size allowed.
+    TooLarge {
+        /// The requested size in bytes.
+        requested: usize,
+        /// The maximum size in bytes.
+        max: usize,
+    },
+    /// The memory region is no longer mapped.
+    NotMapped {
+        /// The virtual address which is not mapped.
+        address: usize,
+    },
 }
 impl fmt::Display for Error {
+    #[allow(clippy::too_many_lines)] // One arm per variant keeps the match exhaustive.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::NotAligned { alignment, address } => {
-                write!(f, "Address 0x{address:X} is not {alignment} byte aligned.",)
+                write!(f, "Address 0x{address:X} is not {alignment} byte aligned.")
+            }
+            Error::EmptyArray => f.write_str("Attempted to create an empty array accessor."),
+            Error::SizeOverflow => {
+                f.write_str("The address or the size of the memory region overflowed.")
+            }
+            Error::OutOfBounds { index, len } => write!(
+                f,
+                "Index {index} is out of bounds for the array of length {len}."
+            ),
+            Error::Timeout => f.write_str("The condition was not satisfied in time."),
+            Error::OutOfRegion { end, max_end } => write!(
+                f,
+                "The memory region ends at 0x{end:X}, which exceeds 0x{max_end:X}."
+            ),
+            Error::LengthMismatch { expected, got } => write!(
+                f,
+                "The length of the array is {got}, but {expected} is expected."
+            ),
+            Error::EmptyRange => f.write_str("The range is empty."),
+            Error::OverlappingIndices => f.write_str("The ranges or the indices overlap."),
+            Error::ZeroSized => {
+                f.write_str("Attempted to create an accessor to a zero-sized type.")
             }
-            Error::EmptyArray => write!(f, "Attempted to create an empty array accessor."),
+            Error::ProbeFailed => f.write_str("The probed value was not the expected one."),
+            Error::TooLarge { requested, max } => write!(
+                f,
+                "{requested} bytes are requested, but at most {max} bytes are allowed."
+            ),
+            Error::NotMapped { address } => write!(f, "Address 0x{address:X} is not mapped."),
         }
     }
 }
@@ -0,0 +1,85 @@
+//! Integer types which registers hold.
+
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+/// An unsigned integer type which a register holds.
+///
+/// This trait is sealed, and cannot be implemented for types outside of this crate.
+pub trait IntReg:
+    Copy
+    + Eq
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + private::Sealed
+{
+    /// The number of bits of the type.
+    const BITS: u32;
+    /// The value 0.
+    const ZERO: Self;
+    /// The value 1.
+    const ONE: Self;
+
+    /// Returns `self + rhs`, wrapping around at the boundary of the type.
+    #[must_use]
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Returns `self - rhs`, wrapping around at the boundary of the type.
+    #[must_use]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Returns `self * rhs`, wrapping around at the boundary of the type.
+    #[must_use]
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Returns `self + rhs`, or `None` if it overflows.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
+
+/// An unsigned integer type whose width is one that processors can usually load and store with a
+/// single aligned access.
+///
+/// This trait is sealed, and cannot be implemented for types outside of this crate.
+pub trait NaturalWidth: IntReg {}
+impl NaturalWidth for u8 {}
+impl NaturalWidth for u16 {}
+impl NaturalWidth for u32 {}
+impl NaturalWidth for u64 {}
+impl NaturalWidth for usize {}
+
+macro_rules! impl_int_reg {
+    ($($t:ty),*) => {
+        $(
+            impl IntReg for $t {
+                const BITS: u32 = <$t>::BITS;
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$t>::wrapping_add(self, rhs)
+                }
+
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$t>::wrapping_sub(self, rhs)
+                }
+
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$t>::wrapping_mul(self, rhs)
+                }
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+            }
+            impl private::Sealed for $t {}
+        )*
+    };
+}
+impl_int_reg!(u8, u16, u32, u64, u128, usize);
+
+mod private {
+    pub trait Sealed {}
+}
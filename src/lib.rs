@@ -3,9 +3,11 @@
 
 pub mod array;
 pub mod error;
+pub mod integer;
 pub mod mapper;
 pub mod marker;
 pub mod single;
+mod volatile;
 
 #[allow(deprecated)]
 pub use {array::Array, single::Single};
@@ -1,6 +1,8 @@
 //! Memory mapper module.
 
-use core::num::NonZeroUsize;
+#[cfg(any(test, feature = "test-utils"))]
+use core::{cell::Cell, ops::Range};
+use core::{fmt, mem, num::NonZeroUsize, ptr};
 
 /// A mapper trait for accessing physical memory.
 pub trait Mapper {
@@ -36,6 +38,22 @@ pub trait Mapper {
     /// }
     unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize;
 
+    /// Maps `bytes` bytes of physical memory region starting from `phys_start`, and returns the
+    /// first virtual address and the number of the bytes actually mapped.
+    ///
+    /// Accessors call this method instead of [`Mapper::map`], and pass the returned number of
+    /// bytes to [`Mapper::unmap`]. Mappers which map more bytes than requested, e.g. in units of
+    /// pages, can override this method so that the whole mapped region is unmapped. Such mappers
+    /// should override [`Mapper::map_region_cacheable`] too. The default implementation calls
+    /// [`Mapper::map`] and returns `bytes` as is.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Mapper::map`].
+    unsafe fn map_region(&mut self, phys_start: usize, bytes: usize) -> (NonZeroUsize, usize) {
+        (self.map(phys_start, bytes), bytes)
+    }
+
     /// Unmaps `bytes` bytes of the virtual memory region starting from `virt_start`.
     ///
     /// # Examples
@@ -56,6 +74,266 @@ pub trait Mapper {
     /// }
     /// ```
     fn unmap(&mut self, virt_start: usize, bytes: usize);
+
+    /// Maps `bytes` bytes of physical memory region starting from `phys_start` with the memory
+    /// type `cache`, and returns the first virtual address.
+    ///
+    /// The default implementation ignores `cache` and calls [`Mapper::map`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Mapper::map`].
+    #[allow(clippy::too_many_arguments)] // `cache` is needed in addition to `map`'s.
+    unsafe fn map_cacheable(
+        &mut self,
+        phys_start: usize,
+        bytes: usize,
+        cache: CacheMode,
+    ) -> NonZeroUsize {
+        let _ = cache;
+        self.map(phys_start, bytes)
+    }
+
+    /// Maps `bytes` bytes of physical memory region starting from `phys_start` with the memory
+    /// type `cache`, and returns the first virtual address and the number of the bytes actually
+    /// mapped.
+    ///
+    /// Accessors created by the `new_with_cache` constructors call this method, and pass the
+    /// returned number of bytes to [`Mapper::unmap`]. This method relates to
+    /// [`Mapper::map_cacheable`] as [`Mapper::map_region`] relates to [`Mapper::map`], so mappers
+    /// which override [`Mapper::map_region`] should override this method too. The default
+    /// implementation calls [`Mapper::map_cacheable`] and returns `bytes` as is.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Mapper::map`].
+    #[allow(clippy::too_many_arguments)] // `cache` is needed in addition to `map_region`'s.
+    unsafe fn map_region_cacheable(
+        &mut self,
+        phys_start: usize,
+        bytes: usize,
+        cache: CacheMode,
+    ) -> (NonZeroUsize, usize) {
+        (self.map_cacheable(phys_start, bytes, cache), bytes)
+    }
+
+    /// Returns a label of the mapped region containing the virtual address `virt`.
+    ///
+    /// This method is intended for debugging, e.g. to correlate a fault address with the region
+    /// an accessor points to. The default implementation returns [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use accessor::mapper::Mapper;
+    /// use core::num::NonZeroUsize;
+    ///
+    /// struct M;
+    /// impl Mapper for M {
+    ///     unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize {
+    ///         todo!()
+    ///     }
+    ///
+    ///     fn unmap(&mut self, virt_start: usize, bytes: usize) {
+    ///         todo!()
+    ///     }
+    ///
+    ///     fn describe(&self, virt: usize) -> Option<&'static str> {
+    ///         (0x1000..0x2000).contains(&virt).then(|| "xHCI Capability Registers")
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(M.describe(0x1800), Some("xHCI Capability Registers"));
+    /// assert_eq!(M.describe(0x3000), None);
+    /// ```
+    fn describe(&self, virt: usize) -> Option<&'static str> {
+        let _ = virt;
+        None
+    }
+
+    /// Returns `true` if `bytes` bytes of virtual memory region starting from `virt_start` are
+    /// still accessible.
+    ///
+    /// Mappers whose mappings may be invalidated while accessors live can override this method so
+    /// that fallible methods such as [`crate::array::Generic::try_iter`] report an error instead
+    /// of faulting. The default implementation returns `true`.
+    fn is_mapped(&self, virt_start: usize, bytes: usize) -> bool {
+        let _ = (virt_start, bytes);
+        true
+    }
+}
+
+/// The memory type of a mapped region.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CacheMode {
+    /// Reads and writes are not cached. This is usually used for memory-mapped I/O.
+    Uncacheable,
+    /// Writes may be combined and delayed. This is usually used for framebuffers.
+    WriteCombining,
+    /// Reads and writes are cached, and writes are written back later.
+    WriteBack,
+}
+
+/// A memory region mapped by a [`Mapper`].
+///
+/// The region is unmapped when this is dropped.
+pub(crate) struct Mapping<M>
+where
+    M: Mapper,
+{
+    phys: usize,
+    virt: usize,
+    bytes: usize,
+    mapper: M,
+    cache: Option<CacheMode>,
+}
+impl<M> Mapping<M>
+where
+    M: Mapper,
+{
+    /// Maps `bytes` bytes of physical memory region starting from `phys_start` with `mapper`.
+    ///
+    /// The region may be larger than `bytes` bytes if [`Mapper::map_region`] maps more bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the conditions of [`Mapper::map`].
+    pub(crate) unsafe fn new(phys_start: usize, bytes: usize, mut mapper: M) -> Self {
+        let (virt, bytes) = mapper.map_region(phys_start, bytes);
+        let virt = virt.get();
+
+        Self {
+            phys: phys_start,
+            virt,
+            bytes,
+            mapper,
+            cache: None,
+        }
+    }
+
+    /// Maps `bytes` bytes of physical memory region starting from `phys_start` with `mapper` and
+    /// the memory type `cache`.
+    ///
+    /// The region may be larger than `bytes` bytes if [`Mapper::map_region_cacheable`] maps more
+    /// bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the conditions of [`Mapper::map`].
+    #[allow(clippy::too_many_arguments)] // `cache` is needed in addition to `new`'s.
+    pub(crate) unsafe fn new_with_cache(
+        phys_start: usize,
+        bytes: usize,
+        mut mapper: M,
+        cache: CacheMode,
+    ) -> Self {
+        let (virt, bytes) = mapper.map_region_cacheable(phys_start, bytes, cache);
+        let virt = virt.get();
+
+        Self {
+            phys: phys_start,
+            virt,
+            bytes,
+            mapper,
+            cache: Some(cache),
+        }
+    }
+
+    /// Returns the first virtual address of the region.
+    pub(crate) fn virt(&self) -> usize {
+        self.virt
+    }
+
+    /// Returns the physical address which the virtual address `virt` in the region is mapped from.
+    pub(crate) fn phys_of(&self, virt: usize) -> usize {
+        debug_assert!(self.contains(virt, 0));
+
+        self.phys + (virt - self.virt)
+    }
+
+    /// Returns `true` if `[virt, virt + bytes)` is in the mapped region.
+    pub(crate) fn contains(&self, virt: usize, bytes: usize) -> bool {
+        virt.checked_sub(self.virt)
+            .and_then(|offset| offset.checked_add(bytes))
+            .is_some_and(|end| end <= self.bytes)
+    }
+
+    /// Returns the memory type requested when the region was mapped, if any.
+    pub(crate) fn cache_mode(&self) -> Option<CacheMode> {
+        self.cache
+    }
+
+    /// Converts the mapper with `f` without unmapping the region.
+    pub(crate) fn map_mapper<N>(self, f: impl FnOnce(M) -> N) -> Mapping<N>
+    where
+        N: Mapper,
+    {
+        let this = mem::ManuallyDrop::new(self);
+
+        Mapping {
+            phys: this.phys,
+            virt: this.virt,
+            bytes: this.bytes,
+            // SAFETY: `this` is never dropped, so the mapper is not used twice.
+            mapper: f(unsafe { ptr::read(&this.mapper) }),
+            cache: this.cache,
+        }
+    }
+
+    /// Returns the mapper which mapped the region.
+    pub(crate) fn mapper(&self) -> &M {
+        &self.mapper
+    }
+
+    /// Converts the mapping into the one with [`Identity`] without unmapping the region.
+    ///
+    /// The original mapper is forgotten without being dropped.
+    pub(crate) fn into_identity(self) -> Mapping<Identity> {
+        let this = mem::ManuallyDrop::new(self);
+
+        Mapping {
+            phys: this.virt,
+            virt: this.virt,
+            bytes: this.bytes,
+            mapper: Identity,
+            cache: this.cache,
+        }
+    }
+
+    /// Unmaps the region and returns the mapper.
+    pub(crate) fn into_mapper(self) -> M {
+        let mut this = mem::ManuallyDrop::new(self);
+        this.unmap();
+
+        // SAFETY: `this` is never dropped, so the mapper is not used twice.
+        unsafe { ptr::read(&this.mapper) }
+    }
+
+    fn unmap(&mut self) {
+        // The exact number of mapped bytes is passed to `unmap`, so that it does not depend on how
+        // the accessors view the region.
+        self.mapper.unmap(self.virt, self.bytes);
+    }
+}
+// Cloning a mapping with `Identity` is sound because `Identity::unmap` does nothing.
+impl Clone for Mapping<Identity> {
+    fn clone(&self) -> Self {
+        Self {
+            phys: self.phys,
+            virt: self.virt,
+            bytes: self.bytes,
+            mapper: Identity,
+            cache: self.cache,
+        }
+    }
+}
+impl<M> Drop for Mapping<M>
+where
+    M: Mapper,
+{
+    fn drop(&mut self) {
+        self.unmap();
+    }
 }
 
 /// The trivial mapper, which maps an address into itself.
@@ -77,3 +355,619 @@ impl Mapper for Identity {
 
     fn unmap(&mut self, _virt_start: usize, _bytes: usize) {}
 }
+
+/// A mapper which never maps memory.
+///
+/// This is useful to name accessor types in generic code, e.g. as a default type parameter,
+/// without creating them. [`Mapper::map`] of this mapper always panics, so creating an accessor
+/// with it fails loudly.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Never;
+impl Mapper for Never {
+    unsafe fn map(&mut self, _phys_start: usize, _bytes: usize) -> NonZeroUsize {
+        panic!("`Never` cannot map memory.")
+    }
+
+    fn unmap(&mut self, _virt_start: usize, _bytes: usize) {}
+}
+
+/// A mapper which maps and unmaps memory with closures.
+///
+/// This is useful to build a mapper inline without implementing [`Mapper`] for a new type.
+///
+/// # Examples
+///
+/// ```
+/// use accessor::mapper::FnMapper;
+/// use accessor::single;
+/// use core::num::NonZeroUsize;
+///
+/// let v = 42_u32;
+/// let base = &v as *const u32 as usize;
+///
+/// // Physical address `p` is mapped to `base + p`.
+/// let mapper = FnMapper::new(
+///     |phys_start, _| NonZeroUsize::new(base + phys_start).unwrap(),
+///     |_, _| {},
+/// );
+///
+/// let a = unsafe { single::ReadOnly::<u32, _>::new(0, mapper) };
+/// assert_eq!(a.read_volatile(), 42);
+/// ```
+#[derive(Clone, Copy)]
+pub struct FnMapper<F, G>
+where
+    F: FnMut(usize, usize) -> NonZeroUsize,
+    G: FnMut(usize, usize),
+{
+    map: F,
+    unmap: G,
+}
+impl<F, G> FnMapper<F, G>
+where
+    F: FnMut(usize, usize) -> NonZeroUsize,
+    G: FnMut(usize, usize),
+{
+    /// Creates a mapper which calls `map` with the physical address and the number of bytes to
+    /// map memory, and `unmap` with the virtual address and the number of bytes to unmap it.
+    pub fn new(map: F, unmap: G) -> Self {
+        Self { map, unmap }
+    }
+}
+impl<F, G> Mapper for FnMapper<F, G>
+where
+    F: FnMut(usize, usize) -> NonZeroUsize,
+    G: FnMut(usize, usize),
+{
+    unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize {
+        (self.map)(phys_start, bytes)
+    }
+
+    fn unmap(&mut self, virt_start: usize, bytes: usize) {
+        (self.unmap)(virt_start, bytes);
+    }
+}
+impl<F, G> fmt::Debug for FnMapper<F, G>
+where
+    F: FnMut(usize, usize) -> NonZeroUsize,
+    G: FnMut(usize, usize),
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnMapper").finish_non_exhaustive()
+    }
+}
+
+/// A mapper which calls a logging function before delegating to another mapper.
+///
+/// The logging function is called with `"map"`, the physical address and the number of bytes
+/// before mapping memory, and with `"unmap"`, the virtual address and the number of bytes before
+/// unmapping it.
+///
+/// # Examples
+///
+/// ```
+/// use accessor::mapper::{Identity, LoggingMapper};
+/// use accessor::single;
+///
+/// fn log(op: &str, addr: usize, bytes: usize) {
+///     println!("{} 0x{:X} ({} bytes)", op, addr, bytes);
+/// }
+///
+/// let v = 42_u32;
+/// let mapper = LoggingMapper::new(Identity, log);
+///
+/// let a = unsafe { single::ReadOnly::<u32, _>::new(&v as *const u32 as usize, mapper) };
+/// assert_eq!(a.read_volatile(), 42);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LoggingMapper<M>
+where
+    M: Mapper,
+{
+    inner: M,
+    log: fn(&str, usize, usize),
+}
+impl<M> LoggingMapper<M>
+where
+    M: Mapper,
+{
+    /// Creates a mapper which calls `log` before delegating mapping and unmapping to `inner`.
+    pub fn new(inner: M, log: fn(&str, usize, usize)) -> Self {
+        Self { inner, log }
+    }
+
+    /// Returns the wrapped mapper.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+}
+impl<M> Mapper for LoggingMapper<M>
+where
+    M: Mapper,
+{
+    unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize {
+        (self.log)("map", phys_start, bytes);
+        self.inner.map(phys_start, bytes)
+    }
+
+    unsafe fn map_region(&mut self, phys_start: usize, bytes: usize) -> (NonZeroUsize, usize) {
+        (self.log)("map", phys_start, bytes);
+        self.inner.map_region(phys_start, bytes)
+    }
+
+    unsafe fn map_cacheable(
+        &mut self,
+        phys_start: usize,
+        bytes: usize,
+        cache: CacheMode,
+    ) -> NonZeroUsize {
+        (self.log)("map", phys_start, bytes);
+        self.inner.map_cacheable(phys_start, bytes, cache)
+    }
+
+    unsafe fn map_region_cacheable(
+        &mut self,
+        phys_start: usize,
+        bytes: usize,
+        cache: CacheMode,
+    ) -> (NonZeroUsize, usize) {
+        (self.log)("map", phys_start, bytes);
+        self.inner.map_region_cacheable(phys_start, bytes, cache)
+    }
+
+    fn unmap(&mut self, virt_start: usize, bytes: usize) {
+        (self.log)("unmap", virt_start, bytes);
+        self.inner.unmap(virt_start, bytes);
+    }
+
+    fn describe(&self, virt: usize) -> Option<&'static str> {
+        self.inner.describe(virt)
+    }
+
+    fn is_mapped(&self, virt_start: usize, bytes: usize) -> bool {
+        self.inner.is_mapped(virt_start, bytes)
+    }
+}
+
+/// A mapper which calls a hook before delegating unmapping to another mapper.
+///
+/// The hook is called with the virtual address and the number of bytes every time the region is
+/// unmapped. This is created by [`crate::single::Generic::with_drop_hook`].
+#[derive(Clone, Copy, Debug)]
+pub struct DropHook<M>
+where
+    M: Mapper,
+{
+    inner: M,
+    hook: fn(usize, usize),
+}
+impl<M> DropHook<M>
+where
+    M: Mapper,
+{
+    /// Creates a mapper which calls `hook` before delegating unmapping to `inner`.
+    pub fn new(inner: M, hook: fn(usize, usize)) -> Self {
+        Self { inner, hook }
+    }
+
+    /// Returns the wrapped mapper.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+}
+impl<M> Mapper for DropHook<M>
+where
+    M: Mapper,
+{
+    unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize {
+        self.inner.map(phys_start, bytes)
+    }
+
+    unsafe fn map_region(&mut self, phys_start: usize, bytes: usize) -> (NonZeroUsize, usize) {
+        self.inner.map_region(phys_start, bytes)
+    }
+
+    unsafe fn map_cacheable(
+        &mut self,
+        phys_start: usize,
+        bytes: usize,
+        cache: CacheMode,
+    ) -> NonZeroUsize {
+        self.inner.map_cacheable(phys_start, bytes, cache)
+    }
+
+    unsafe fn map_region_cacheable(
+        &mut self,
+        phys_start: usize,
+        bytes: usize,
+        cache: CacheMode,
+    ) -> (NonZeroUsize, usize) {
+        self.inner.map_region_cacheable(phys_start, bytes, cache)
+    }
+
+    fn unmap(&mut self, virt_start: usize, bytes: usize) {
+        (self.hook)(virt_start, bytes);
+        self.inner.unmap(virt_start, bytes);
+    }
+
+    fn describe(&self, virt: usize) -> Option<&'static str> {
+        self.inner.describe(virt)
+    }
+
+    fn is_mapped(&self, virt_start: usize, bytes: usize) -> bool {
+        self.inner.is_mapped(virt_start, bytes)
+    }
+}
+
+/// A mapper for tests which hands out sequential virtual addresses from a fixed base.
+///
+/// Mapping `bytes` bytes returns `base + cursor` and advances the cursor by `bytes`, so the regions
+/// mapped by this mapper never overlap. The mapper is shared by reference, so that multiple
+/// accessors can use it at the same time.
+///
+/// The mapper records the mapped regions, so that tests can inspect them. Unmapping a region which
+/// is not mapped, or is already unmapped, panics.
+///
+/// The returned addresses do not point to the physical memory, so the accessors using this mapper
+/// must not read or write values.
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Debug)]
+pub struct BumpMapper {
+    base: usize,
+    cursor: Cell<usize>,
+    regions: [Cell<Option<BumpRegion>>; BumpMapper::MAX_REGIONS],
+}
+#[cfg(any(test, feature = "test-utils"))]
+impl BumpMapper {
+    /// The maximum number of regions which a mapper can map.
+    pub const MAX_REGIONS: usize = 16;
+
+    /// Creates a mapper which hands out virtual addresses from `base`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `base == 0`.
+    #[must_use]
+    pub fn new(base: usize) -> Self {
+        assert_ne!(base, 0);
+
+        Self {
+            base,
+            cursor: Cell::new(0),
+            regions: core::array::from_fn(|_| Cell::new(None)),
+        }
+    }
+
+    /// Returns the total number of the mapped bytes.
+    pub fn mapped_bytes(&self) -> usize {
+        self.cursor.get()
+    }
+
+    /// Returns the total number of the unmapped bytes.
+    pub fn unmapped_bytes(&self) -> usize {
+        self.recorded()
+            .filter(|r| r.unmapped)
+            .map(|r| r.bytes)
+            .sum()
+    }
+
+    /// Returns an iterator over the mapped regions in the order of mapping, including the unmapped
+    /// ones.
+    pub fn mapped_regions(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.recorded().map(BumpRegion::range)
+    }
+
+    /// Returns an iterator over the regions which are mapped and not unmapped yet.
+    pub fn live_regions(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.recorded()
+            .filter(|r| !r.unmapped)
+            .map(BumpRegion::range)
+    }
+
+    fn recorded(&self) -> impl Iterator<Item = BumpRegion> + '_ {
+        self.regions.iter().map_while(Cell::get)
+    }
+}
+#[cfg(any(test, feature = "test-utils"))]
+impl Mapper for &BumpMapper {
+    unsafe fn map(&mut self, _phys_start: usize, bytes: usize) -> NonZeroUsize {
+        let virt = self
+            .base
+            .checked_add(self.cursor.get())
+            .expect("The virtual address overflowed.");
+        virt.checked_add(bytes)
+            .expect("The virtual address overflowed.");
+
+        let slot = self
+            .regions
+            .iter()
+            .find(|r| r.get().is_none())
+            .expect("Too many regions are mapped.");
+        slot.set(Some(BumpRegion {
+            virt,
+            bytes,
+            unmapped: false,
+        }));
+        self.cursor.set(self.cursor.get() + bytes);
+
+        NonZeroUsize::new(virt).unwrap()
+    }
+
+    fn unmap(&mut self, virt_start: usize, bytes: usize) {
+        let region = BumpRegion {
+            virt: virt_start,
+            bytes,
+            unmapped: false,
+        };
+        let slot = self
+            .regions
+            .iter()
+            .find(|r| r.get() == Some(region))
+            .unwrap_or_else(|| panic!("{:X?} is not mapped.", region.range()));
+
+        slot.set(Some(BumpRegion {
+            unmapped: true,
+            ..region
+        }));
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct BumpRegion {
+    virt: usize,
+    bytes: usize,
+    unmapped: bool,
+}
+#[cfg(any(test, feature = "test-utils"))]
+impl BumpRegion {
+    fn range(self) -> Range<usize> {
+        self.virt..self.virt + self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{array, single};
+
+    #[test]
+    fn test_fn_mapper() {
+        let v: u32 = 42;
+        let base = addr(&v);
+        let unmapped = Cell::new(None);
+
+        let mapper = FnMapper::new(
+            |phys_start, _| NonZeroUsize::new(base + phys_start).unwrap(),
+            |virt_start, bytes| unmapped.set(Some((virt_start, bytes))),
+        );
+
+        let a = unsafe { single::ReadOnly::<u32, _>::new(0, mapper) };
+        assert_eq!(a.read_volatile(), 42);
+
+        drop(a);
+        assert_eq!(unmapped.get(), Some((base, 4)));
+    }
+
+    #[test]
+    fn test_logging_mapper() {
+        extern crate std;
+        use std::{sync::Mutex, vec::Vec};
+
+        static LOG: Mutex<Vec<(&'static str, usize, usize)>> = Mutex::new(Vec::new());
+
+        fn log(op: &str, addr: usize, bytes: usize) {
+            let op = match op {
+                "map" => "map",
+                "unmap" => "unmap",
+                _ => unreachable!(),
+            };
+            LOG.lock().unwrap().push((op, addr, bytes));
+        }
+
+        let v: u32 = 42;
+        let base = addr(&v);
+
+        let a = unsafe { single::ReadOnly::<u32, _>::new(base, LoggingMapper::new(Identity, log)) };
+        assert_eq!(a.read_volatile(), 42);
+        assert_eq!(*LOG.lock().unwrap(), [("map", base, 4)]);
+
+        drop(a);
+        assert_eq!(*LOG.lock().unwrap(), [("map", base, 4), ("unmap", base, 4)]);
+    }
+
+    #[test]
+    fn test_map_cacheable() {
+        struct Recording<'a>(&'a Cell<Option<CacheMode>>);
+        impl Mapper for Recording<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            unsafe fn map_cacheable(
+                &mut self,
+                phys_start: usize,
+                bytes: usize,
+                cache: CacheMode,
+            ) -> NonZeroUsize {
+                self.0.set(Some(cache));
+                self.map(phys_start, bytes)
+            }
+
+            fn unmap(&mut self, _: usize, _: usize) {}
+        }
+
+        let v: [u32; 2] = [1, 2];
+        let mode = Cell::new(None);
+
+        let a = unsafe {
+            single::ReadOnly::<u32, _>::new_with_cache(
+                addr(&v),
+                Recording(&mode),
+                CacheMode::Uncacheable,
+            )
+        };
+        assert_eq!(mode.get(), Some(CacheMode::Uncacheable));
+        assert_eq!(a.cache_mode(), Some(CacheMode::Uncacheable));
+        assert_eq!(a.read_volatile(), 1);
+
+        let b = unsafe {
+            array::ReadOnly::<u32, _>::new_with_cache(
+                addr(&v),
+                2,
+                Recording(&mode),
+                CacheMode::WriteCombining,
+            )
+        };
+        assert_eq!(mode.get(), Some(CacheMode::WriteCombining));
+        assert_eq!(b.cache_mode(), Some(CacheMode::WriteCombining));
+        assert_eq!(b.read_volatile_at(1), 2);
+
+        let c = unsafe { single::ReadOnly::<u32, _>::new(addr(&v), Identity) };
+        assert_eq!(c.cache_mode(), None);
+    }
+
+    #[test]
+    fn test_bump_mapper() {
+        let m = BumpMapper::new(0x1000);
+
+        let a = unsafe { single::ReadOnly::<u32, _>::new(0x8000, &m) };
+        let b = unsafe { array::ReadOnly::<u32, _>::new(0x8000, 4, &m) };
+
+        assert_eq!(a.virt(), 0x1000);
+        assert_eq!(b.virt(), 0x1004);
+        assert_eq!(m.mapped_bytes(), 20);
+
+        let regions = [0x1000..0x1004, 0x1004..0x1014];
+        assert!(m.mapped_regions().eq(regions.iter().cloned()));
+        assert!(regions[0].end <= regions[1].start);
+
+        drop(a);
+        assert!(m.live_regions().eq(core::iter::once(0x1004..0x1014)));
+
+        drop(b);
+        assert_eq!(m.live_regions().count(), 0);
+        assert_eq!(m.unmapped_bytes(), 20);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bump_mapper_unmap_unmapped() {
+        let m = BumpMapper::new(0x1000);
+        let mut r = &m;
+
+        unsafe { r.map(0x8000, 4) };
+        r.unmap(0x1000, 4);
+        r.unmap(0x1000, 4);
+    }
+
+    #[test]
+    fn test_map_region() {
+        struct PageMapper<'a> {
+            unmapped: &'a Cell<usize>,
+        }
+        impl Mapper for PageMapper<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            unsafe fn map_region(
+                &mut self,
+                phys_start: usize,
+                bytes: usize,
+            ) -> (NonZeroUsize, usize) {
+                (self.map(phys_start, bytes), bytes.div_ceil(4096) * 4096)
+            }
+
+            fn unmap(&mut self, _: usize, bytes: usize) {
+                self.unmapped.set(bytes);
+            }
+        }
+
+        let unmapped = Cell::new(0);
+        let v = 42_u32;
+        let a = unsafe {
+            single::ReadOnly::<u32, _>::new(
+                addr(&v),
+                PageMapper {
+                    unmapped: &unmapped,
+                },
+            )
+        };
+        assert_eq!(a.read_volatile(), 42);
+
+        drop(a);
+        assert_eq!(unmapped.get(), 4096);
+    }
+
+    #[test]
+    fn test_map_region_cacheable() {
+        struct PageMapper<'a> {
+            cache: &'a Cell<Option<CacheMode>>,
+            unmapped: &'a Cell<usize>,
+        }
+        impl Mapper for PageMapper<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            unsafe fn map_region_cacheable(
+                &mut self,
+                phys_start: usize,
+                bytes: usize,
+                cache: CacheMode,
+            ) -> (NonZeroUsize, usize) {
+                self.cache.set(Some(cache));
+                (self.map(phys_start, bytes), bytes.div_ceil(4096) * 4096)
+            }
+
+            fn unmap(&mut self, _: usize, bytes: usize) {
+                self.unmapped.set(bytes);
+            }
+        }
+
+        let cache = Cell::new(None);
+        let unmapped = Cell::new(0);
+        let v = [1_u32, 2];
+        let a = unsafe {
+            array::ReadOnly::<u32, _>::new_with_cache(
+                addr(&v),
+                2,
+                PageMapper {
+                    cache: &cache,
+                    unmapped: &unmapped,
+                },
+                CacheMode::Uncacheable,
+            )
+        };
+        assert_eq!(cache.get(), Some(CacheMode::Uncacheable));
+        assert_eq!(a.read_volatile_at(1), 2);
+
+        drop(a);
+        assert_eq!(unmapped.get(), 4096);
+    }
+
+    #[test]
+    fn test_never_in_type() {
+        type Unused = single::ReadOnly<u32, Never>;
+
+        fn takes(a: Option<Unused>) -> bool {
+            a.is_none()
+        }
+
+        assert!(takes(None));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_never_map() {
+        let v = 0_u32;
+        let _ = unsafe { single::ReadOnly::<u32, _>::new(addr(&v), Never) };
+    }
+
+    fn addr<T>(v: &T) -> usize {
+        let v: *const T = v;
+
+        v as usize
+    }
+}
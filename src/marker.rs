@@ -2,7 +2,19 @@
 
 /// A marker trait representing that the type implementing this can be used to specify the type of
 /// an accessor (whether it can read a value, write a value, or both).
-pub trait AccessorTypeSpecifier {}
+///
+/// Implementers must set [`AccessorTypeSpecifier::READABLE`] to `true` if and only if they
+/// implement [`Readable`], and [`AccessorTypeSpecifier::WRITABLE`] to `true` if and only if they
+/// implement [`Writable`].
+pub trait AccessorTypeSpecifier {
+    /// `true` if an accessor whose type is specified by `Self` can read a value.
+    ///
+    /// Generic code can branch on this at compile time.
+    const READABLE: bool;
+
+    /// `true` if an accessor whose type is specified by `Self` can write a value.
+    const WRITABLE: bool;
+}
 
 /// A marker trait representing that the accessor can read a value.
 pub trait Readable: AccessorTypeSpecifier {}
@@ -10,21 +22,143 @@ pub trait Readable: AccessorTypeSpecifier {}
 /// A marker trait representing that the accessor can write a value.
 pub trait Writable: AccessorTypeSpecifier {}
 
+/// A marker trait representing that the accessor can both read and write a value.
+///
+/// This trait is implemented for all types implementing both [`Readable`] and [`Writable`], and
+/// can be used as a shorthand of `Readable + Writable`. It cannot be implemented manually.
+///
+/// ```compile_fail
+/// use accessor::marker::{ReadOnly, ReadableWritable};
+///
+/// fn f<A: ReadableWritable>() {}
+///
+/// // A read-only accessor type cannot be used where both reading and writing are required.
+/// f::<ReadOnly>();
+/// ```
+pub trait ReadableWritable: Readable + Writable + private::Sealed {}
+impl<A> ReadableWritable for A where A: Readable + Writable {}
+
 /// A marker enum representing that the accessor can only read a value.
 #[derive(Copy, Clone, Debug)]
 pub enum ReadOnly {}
-impl AccessorTypeSpecifier for ReadOnly {}
+impl AccessorTypeSpecifier for ReadOnly {
+    const READABLE: bool = true;
+    const WRITABLE: bool = false;
+}
 impl Readable for ReadOnly {}
 
 /// A marker enum representing that the accessor can only write a value.
 #[derive(Copy, Clone, Debug)]
 pub enum WriteOnly {}
-impl AccessorTypeSpecifier for WriteOnly {}
+impl AccessorTypeSpecifier for WriteOnly {
+    const READABLE: bool = false;
+    const WRITABLE: bool = true;
+}
 impl Writable for WriteOnly {}
 
 /// A marker enum representing that the accessor can both read and write a value.
 #[derive(Copy, Clone, Debug)]
 pub enum ReadWrite {}
-impl AccessorTypeSpecifier for ReadWrite {}
+impl AccessorTypeSpecifier for ReadWrite {
+    const READABLE: bool = true;
+    const WRITABLE: bool = true;
+}
 impl Readable for ReadWrite {}
 impl Writable for ReadWrite {}
+
+/// A marker trait representing that an accessor whose type is specified by `Self` can be
+/// converted into the one whose type is specified by `To`.
+///
+/// An accessor can only lose its capabilities by a conversion. For example, a readable and
+/// writable accessor can be converted into a read-only one, but a read-only accessor can never
+/// be converted into a write-only one.
+///
+/// ```compile_fail
+/// use accessor::mapper::Identity;
+/// use accessor::single;
+///
+/// let v = 42_u32;
+/// let a = unsafe { single::ReadOnly::<u32, _>::new(&v as *const u32 as usize, Identity) };
+///
+/// // A read-only accessor cannot be downgraded into a write-only one.
+/// let _ = a.into_write_only();
+/// ```
+pub trait Downgrade<To: AccessorTypeSpecifier>: AccessorTypeSpecifier {}
+impl Downgrade<ReadOnly> for ReadOnly {}
+impl Downgrade<WriteOnly> for WriteOnly {}
+impl Downgrade<ReadWrite> for ReadWrite {}
+impl Downgrade<ReadOnly> for ReadWrite {}
+impl Downgrade<WriteOnly> for ReadWrite {}
+
+/// A marker trait representing that a value whose bytes are all zero is a valid value of the type.
+///
+/// [`crate::array::Generic::new_zeroed`] requires this trait. It is implemented for the primitive
+/// integers, floating-point numbers, and arrays of them.
+///
+/// # Safety
+///
+/// The implementer must ensure that a value whose bytes are all zero is a valid value of `Self`.
+/// For example, a `#[repr(C)]` struct whose fields all implement this trait satisfies this
+/// condition, but a reference or [`core::num::NonZeroU32`] does not.
+///
+/// # Examples
+///
+/// ```
+/// use accessor::marker::Zeroable;
+///
+/// #[derive(Copy, Clone)]
+/// #[repr(C)]
+/// struct Descriptor {
+///     addr: u64,
+///     len: u32,
+///     flags: u16,
+/// }
+///
+/// // SAFETY: All fields are integers, so zero bytes are a valid value.
+/// unsafe impl Zeroable for Descriptor {}
+/// ```
+pub unsafe trait Zeroable {}
+macro_rules! impl_zeroable {
+    ($($t:ty),*) => {
+        $(
+            // SAFETY: Zero bytes are a valid value of a primitive number.
+            unsafe impl Zeroable for $t {}
+        )*
+    };
+}
+impl_zeroable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+// SAFETY: An array has no padding bytes between the elements, and the zero bytes are a valid
+// value of each element.
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] {}
+
+mod private {
+    use super::{Readable, Writable};
+
+    pub trait Sealed {}
+    impl<A> Sealed for A where A: Readable + Writable {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capability_consts() {
+        fn capabilities<A: AccessorTypeSpecifier>() -> (bool, bool) {
+            (A::READABLE, A::WRITABLE)
+        }
+
+        assert_eq!(capabilities::<ReadOnly>(), (true, false));
+        assert_eq!(capabilities::<WriteOnly>(), (false, true));
+        assert_eq!(capabilities::<ReadWrite>(), (true, true));
+    }
+
+    #[test]
+    fn test_readable_writable() {
+        fn both<A: ReadableWritable>() -> (bool, bool) {
+            (A::READABLE, A::WRITABLE)
+        }
+
+        assert_eq!(both::<ReadWrite>(), (true, true));
+    }
+}
@@ -2,11 +2,22 @@
 
 use {
     crate::{
+        array,
         error::Error,
-        mapper::Mapper,
-        marker::{self, AccessorTypeSpecifier, Readable, Writable},
+        integer::{IntReg, NaturalWidth},
+        mapper::{CacheMode, DropHook, Identity, Mapper, Mapping},
+        marker::{self, AccessorTypeSpecifier, Downgrade, Readable, Writable},
+        volatile,
+    },
+    core::{
+        cell::UnsafeCell,
+        fmt,
+        hash::Hash,
+        marker::PhantomData,
+        mem,
+        ptr::{self, NonNull},
+        sync::atomic::{self, Ordering},
     },
-    core::{fmt, hash::Hash, marker::PhantomData, mem, ptr},
 };
 
 /// An alias of [`ReadWrite`].
@@ -51,7 +62,7 @@ pub type WriteOnly<T, M> = Generic<T, M, marker::WriteOnly>;
 /// let mut a = unsafe { single::ReadWrite::<i32, M>::new(0x1000, mapper) };
 ///
 /// // Read a value.
-/// a.read_volatile();
+/// let _ = a.read_volatile();
 ///
 /// // Write 42.
 /// a.write_volatile(42);
@@ -67,15 +78,35 @@ where
     A: AccessorTypeSpecifier,
 {
     virt: usize,
+    mapping: Mapping<M>,
     _marker: PhantomData<T>,
     _readable_writable: PhantomData<A>,
-    mapper: M,
 }
 impl<T, M, A> Generic<T, M, A>
 where
     M: Mapper,
     A: AccessorTypeSpecifier,
 {
+    /// Creates a new accessor to an element of type `T` at the physical address `phys_base`,
+    /// mapping the memory with the memory type `cache`.
+    ///
+    /// The memory is mapped by [`Mapper::map_cacheable`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::new`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics on the same conditions as [`Generic::new`].
+    pub unsafe fn new_with_cache(phys_base: usize, mapper: M, cache: CacheMode) -> Self {
+        Self::assert_valid(phys_base);
+
+        let mapping = Mapping::new_with_cache(phys_base, mem::size_of::<T>(), mapper, cache);
+
+        Self::from_raw_parts(mapping.virt(), mapping)
+    }
+
     /// Creates a new accessor to an element of type `T` at the physical address `phys_base`.
     ///
     /// # Safety
@@ -83,23 +114,19 @@ where
     /// The caller must ensure the following conditions:
     /// - The value at the physical address `phys_base` is valid.
     /// - Any other accessors except the one returned by this method must not access the value
-    /// while the returned one lives.
+    ///   while the returned one lives.
     ///
     /// # Panics
     ///
-    /// This method panics if `phys_base` is not aligned as the type `T` requires.
-    pub unsafe fn new(phys_base: usize, mut mapper: M) -> Self {
-        assert!(super::is_aligned::<T>(phys_base));
+    /// This method panics if
+    /// - `T` is a zero-sized type.
+    /// - `phys_base` is not aligned as the type `T` requires.
+    pub unsafe fn new(phys_base: usize, mapper: M) -> Self {
+        Self::assert_valid(phys_base);
 
-        let bytes = mem::size_of::<T>();
-        let virt = mapper.map(phys_base, bytes).get();
+        let mapping = Mapping::new(phys_base, mem::size_of::<T>(), mapper);
 
-        Self {
-            virt,
-            _marker: PhantomData,
-            _readable_writable: PhantomData,
-            mapper,
-        }
+        Self::from_raw_parts(mapping.virt(), mapping)
     }
 
     /// Creates a new accessor to an element of type `T` at the physical address `phys_base`.
@@ -109,14 +136,17 @@ where
     /// The caller must ensure the following conditions:
     /// - The value at the physical address `phys_base` is valid.
     /// - Any other accessors except the one returned by this method must not access the value
-    /// while the returned one lives.
+    ///   while the returned one lives.
     ///
     /// # Errors
     ///
-    /// This method may return a [`Error::NotAligned`] error if `phys_base` is not aligned as the
-    /// type `T` requires.
+    /// This method may return an error.
+    /// - [`Error::ZeroSized`] - `T` is a zero-sized type.
+    /// - [`Error::NotAligned`] - `phys_base` is not aligned as the type `T` requires.
     pub unsafe fn try_new(phys_base: usize, mapper: M) -> Result<Self, Error> {
-        if super::is_aligned::<T>(phys_base) {
+        if mem::size_of::<T>() == 0 {
+            Err(Error::ZeroSized)
+        } else if super::is_aligned::<T>(phys_base) {
             Ok(Self::new(phys_base, mapper))
         } else {
             Err(Error::NotAligned {
@@ -125,203 +155,2114 @@ where
             })
         }
     }
-}
-impl<T, M, A> Generic<T, M, A>
-where
-    M: Mapper,
-    A: Readable,
-{
-    /// Reads a value from the address that the accessor points to.
-    pub fn read_volatile(&self) -> T {
-        // SAFETY: `Accessor::new` ensures that `self.virt` is aligned properly.
-        unsafe { ptr::read_volatile(self.virt as *const _) }
-    }
 
-    /// Alias of [`Generic::read_volatile`].
-    #[deprecated(since = "0.3.1", note = "use `read_volatile`")]
-    pub fn read(&self) -> T {
-        self.read_volatile()
-    }
-}
-impl<T, M, A> Generic<T, M, A>
-where
-    M: Mapper,
-    A: Writable,
-{
-    /// Writes a value to the address that the accessor points to.
-    pub fn write_volatile(&mut self, v: T) {
-        // SAFETY: `Accessor::new` ensures that `self.virt` is aligned properly.
-        unsafe {
-            ptr::write_volatile(self.virt as *mut _, v);
-        }
+    /// Creates a new accessor to an element of type `T` at the physical address `ptr` points to.
+    ///
+    /// The address of `ptr` is regarded as a physical address and is mapped with `mapper` as
+    /// [`Generic::new`] does. Use [`Identity`] if `ptr` is already accessible.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::new`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `ptr` is not aligned as the type `T` requires.
+    pub unsafe fn from_non_null(ptr: NonNull<T>, mapper: M) -> Self {
+        Self::new(ptr.as_ptr() as usize, mapper)
     }
 
-    /// Alias of [`Generic::write_volatile`].
-    #[deprecated(since = "0.3.1", note = "use `write_volatile`")]
-    pub fn write(&mut self, v: T) {
-        self.write_volatile(v);
-    }
-}
-impl<T, M, A> Generic<T, M, A>
-where
-    M: Mapper,
-    A: Readable + Writable,
-{
-    /// Updates a value that the accessor points by reading it, modifying it, and writing it.
+    /// Converts the accessor into a read-only one.
     ///
-    /// Note that some MMIO regions (e.g. the Command Ring Pointer field of the Command
-    /// Ring Control Register of the xHCI) may return 0 regardless of the actual values of the
-    /// fields. For these regions, this operation should be called only once.
-    pub fn update_volatile<U>(&mut self, f: U)
+    /// This method does not remap the memory region.
+    pub fn into_read_only(self) -> Generic<T, M, marker::ReadOnly>
     where
-        U: FnOnce(&mut T),
+        A: Downgrade<marker::ReadOnly>,
     {
-        let mut v = self.read_volatile();
-        f(&mut v);
-        self.write_volatile(v);
+        self.into_type_specifier()
     }
 
-    /// Alias of [`Generic::update_volatile`].
-    #[deprecated(since = "0.3.1", note = "use `update_volatile`")]
-    pub fn update<U>(&mut self, f: U)
+    /// Converts the accessor into a write-only one.
+    ///
+    /// This method does not remap the memory region.
+    pub fn into_write_only(self) -> Generic<T, M, marker::WriteOnly>
     where
-        U: FnOnce(&mut T),
+        A: Downgrade<marker::WriteOnly>,
     {
-        self.update_volatile(f);
+        self.into_type_specifier()
     }
-}
-impl<T, M, A> fmt::Debug for Generic<T, M, A>
-where
-    T: fmt::Debug,
-    M: Mapper,
-    A: Readable,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.read_volatile())
+
+    /// Returns the virtual address of the value.
+    pub fn virt(&self) -> usize {
+        self.virt
     }
-}
-impl<T, M, A> PartialEq for Generic<T, M, A>
-where
-    T: PartialEq,
-    M: Mapper,
-    A: Readable,
-{
-    fn eq(&self, other: &Self) -> bool {
-        self.read_volatile().eq(&other.read_volatile())
+
+    /// Returns `true` if `self` and `other` point to the same virtual address.
+    ///
+    /// Unlike [`PartialEq`], this method does not read the values, so it is safe to use with
+    /// registers whose reads have side effects.
+    pub fn points_to_same(&self, other: &Self) -> bool {
+        self.virt == other.virt
     }
-}
-impl<T, M, A> Eq for Generic<T, M, A>
-where
-    T: Eq,
-    M: Mapper,
-    A: Readable,
-{
-}
-impl<T, M, A> PartialOrd for Generic<T, M, A>
-where
-    T: PartialOrd,
-    M: Mapper,
-    A: Readable,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        self.read_volatile().partial_cmp(&other.read_volatile())
+
+    /// Returns the memory type passed to [`Generic::new_with_cache`], or [`None`] if the accessor
+    /// is created by another constructor.
+    pub fn cache_mode(&self) -> Option<CacheMode> {
+        self.mapping.cache_mode()
     }
-}
-impl<T, M, A> Ord for Generic<T, M, A>
-where
-    T: Ord,
-    M: Mapper,
-    A: Readable,
-{
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.read_volatile().cmp(&other.read_volatile())
+
+    /// Returns the label of the region this accessor points to, given by [`Mapper::describe`].
+    pub fn debug_describe(&self) -> Option<&'static str> {
+        self.mapping.mapper().describe(self.virt)
     }
-}
-impl<T, M, A> Hash for Generic<T, M, A>
-where
-    T: Hash,
-    M: Mapper,
-    A: Readable,
-{
-    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
-        self.read_volatile().hash(state);
+
+    /// Creates an accessor to a value of type `U` at `offset` bytes from the address this
+    /// accessor points to.
+    ///
+    /// The returned accessor uses [`Identity`], so it does not map the memory region again.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The value of `U` at the address is valid.
+    /// - The memory region of the returned accessor is mapped while it lives. Note that it does
+    ///   not borrow `self`, so it may outlive this accessor.
+    /// - Any other accessors except the one returned by this method must not access the value
+    ///   while the returned one lives.
+    ///
+    /// # Errors
+    ///
+    /// This method may return an error.
+    /// - [`Error::SizeOverflow`] - The address or the end of the value of `U` overflows.
+    /// - [`Error::NotAligned`] - The address is not aligned as the type `U` requires.
+    pub unsafe fn try_offset<U>(&self, offset: usize) -> Result<Generic<U, Identity, A>, Error> {
+        let virt = self
+            .virt
+            .checked_add(offset)
+            .filter(|virt| virt.checked_add(mem::size_of::<U>()).is_some())
+            .ok_or(Error::SizeOverflow)?;
+
+        Generic::try_new(virt, Identity)
     }
-}
-impl<T, M, A> Drop for Generic<T, M, A>
-where
-    M: Mapper,
-    A: AccessorTypeSpecifier,
-{
-    fn drop(&mut self) {
-        let bytes = mem::size_of::<T>();
-        self.mapper.unmap(self.virt, bytes);
+
+    /// Consumes the accessor without unmapping the memory region, and returns the virtual address
+    /// of the value.
+    ///
+    /// This is useful for a memory region which must be mapped while the program runs. Neither
+    /// [`Mapper::unmap`] is called nor the mapper is dropped.
+    pub fn leak(self) -> usize {
+        let virt = self.virt;
+        mem::forget(self);
+        virt
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::num::NonZeroUsize;
+    /// Creates an accessor to the value at the virtual address `virt` in `mapping`.
+    ///
+    /// The returned accessor unmaps the region of `mapping` when dropped.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the value at `virt` is valid and is in the region of `mapping`,
+    /// and `virt` is aligned as the type `T` requires.
+    pub(crate) unsafe fn from_raw_parts(virt: usize, mapping: Mapping<M>) -> Self {
+        debug_assert!(mapping.contains(virt, mem::size_of::<T>()));
 
-    struct M;
-    impl Mapper for M {
-        unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
-            NonZeroUsize::new(phys_start).unwrap()
+        Self {
+            virt,
+            mapping,
+            _marker: PhantomData,
+            _readable_writable: PhantomData,
         }
+    }
 
-        fn unmap(&mut self, _: usize, _: usize) {}
+    /// Panics if the value at `phys_base` cannot be accessed, as documented in [`Generic::new`].
+    fn assert_valid(phys_base: usize) {
+        assert_ne!(mem::size_of::<T>(), 0, "`T` must not be a zero-sized type.");
+        assert!(super::is_aligned::<T>(phys_base));
     }
 
-    #[test]
-    fn test_read_volatile() {
-        let v: u32 = 42;
-        let a = unsafe { ReadWrite::<u32, M>::new(addr(&v), M) };
+    /// Sets `hook`, which is called with the virtual address and the size in bytes of the mapped
+    /// region just before the region is unmapped, e.g. when the accessor is dropped.
+    ///
+    /// This is useful to, for example, flush caches or log the accessor. The hook is held by the
+    /// returned mapper, [`DropHook`], so accessors without hooks have no overhead. The hook is
+    /// called only when the mapper unmaps a region:
+    /// - [`Generic::following_array`] calls it for the region of the value, and the returned array
+    ///   accessor calls it again for its own region.
+    /// - [`Generic::into_identity`] forgets it, because the region is not unmapped.
+    ///
+    /// If hooks are set multiple times, all of them are called, the last one first.
+    pub fn with_drop_hook(self, hook: fn(usize, usize)) -> Generic<T, DropHook<M>, A> {
+        let mapping = self.mapping.map_mapper(|m| DropHook::new(m, hook));
 
-        assert_eq!(a.read_volatile(), 42);
+        // SAFETY: The region is still mapped at the same virtual address.
+        unsafe { Generic::from_raw_parts(self.virt, mapping) }
     }
 
-    #[test]
-    fn test_write_volatile() {
-        let mut v: u32 = 0;
-        let mut a = unsafe { ReadWrite::<u32, M>::new(addr(&mut v), M) };
+    /// Converts the accessor into an accessor to `[E; count]` which immediately follows the
+    /// value.
+    ///
+    /// The region of the value is unmapped, and the region of the array is mapped with the same
+    /// mapper.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The array just after the value is valid.
+    /// - Any other accessors except the one returned by this method must not access the array
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if
+    /// - the array is not aligned as the type `E` requires.
+    /// - `count == 0`.
+    pub unsafe fn following_array<E>(self, count: usize) -> array::Generic<E, M, A>
+    where
+        E: Copy,
+    {
+        let phys = self.mapping.phys_of(self.virt) + mem::size_of::<T>();
 
-        a.write_volatile(42);
-        assert_eq!(v, 42);
+        array::Generic::new(phys, count, self.mapping.into_mapper())
     }
 
-    #[test]
-    fn test_update_volatile() {
-        let mut v: u32 = 42;
-        let mut a = unsafe { ReadWrite::<u32, M>::new(addr(&mut v), M) };
+    /// Converts the accessor into the one using [`Identity`] without unmapping the region.
+    ///
+    /// The original mapper is forgotten without calling [`Mapper::unmap`] or being dropped, so the
+    /// region stays mapped after the returned accessor is dropped. The caller takes the
+    /// responsibility for unmapping it and releasing the resources of the mapper if needed.
+    pub fn into_identity(self) -> Generic<T, Identity, A> {
+        // SAFETY: The region is still mapped at the same virtual address.
+        unsafe { Generic::from_raw_parts(self.virt, self.mapping.into_identity()) }
+    }
 
-        a.update_volatile(|v| *v *= 2);
-        assert_eq!(v, 84);
+    /// Returns the number of bits of `T`.
+    #[must_use]
+    pub fn bit_width() -> u32
+    where
+        T: IntReg,
+    {
+        T::BITS
     }
 
-    #[test]
-    #[should_panic]
-    fn test_not_aligned() {
-        let v: u32 = 42;
+    /// Converts the accessor into an array accessor which regards the value as an array of `E`.
+    ///
+    /// The length of the array is `size_of::<T>() / size_of::<E>()`. This method does not remap
+    /// the memory region. Use [`Generic::as_array`] if `T` is already an array type.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that each element of the array is a valid value of `E`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if
+    /// - the size of `E` is zero, or the size of `T` is not a non-zero multiple of it.
+    /// - the value is not aligned as the type `E` requires.
+    pub unsafe fn view_as_array_of<E>(self) -> array::Generic<E, M, A>
+    where
+        E: Copy,
+    {
+        assert_ne!(mem::size_of::<E>(), 0);
+        assert_eq!(mem::size_of::<T>() % mem::size_of::<E>(), 0);
+        assert!(super::is_aligned::<E>(self.virt));
 
-        let _ = unsafe { ReadWrite::<u32, M>::new(addr(&v) + 1, M) };
+        let len = mem::size_of::<T>() / mem::size_of::<E>();
+        assert_ne!(len, 0);
+
+        array::Generic::from_raw_parts(self.virt, len, self.mapping)
     }
 
-    #[test]
-    fn test_err_not_aligned() {
-        let v: u32 = 42;
+    /// Converts the accessor into a readable and writable one without remapping the memory region.
+    ///
+    /// This is the unsafe counterpart of [`Generic::into_read_only`] and
+    /// [`Generic::into_write_only`], and upgrades a read-only or a write-only accessor.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the value can be both read and written, e.g. according to the
+    /// specification of the hardware, and that reading and writing the value has no undesired
+    /// side effects.
+    pub unsafe fn into_read_write(self) -> Generic<T, M, marker::ReadWrite> {
+        self.into_type_specifier()
+    }
 
-        let r = unsafe { ReadWrite::<u32, M>::try_new(addr(&v) + 1, M) };
-        assert_eq!(
-            r,
-            Err(Error::NotAligned {
-                alignment: mem::align_of::<u32>(),
-                address: addr(&v) + 1,
-            })
-        );
+    fn into_type_specifier<B>(self) -> Generic<T, M, B>
+    where
+        B: AccessorTypeSpecifier,
+    {
+        // SAFETY: Only the type specifier is changed.
+        unsafe { Generic::from_raw_parts(self.virt, self.mapping) }
     }
+}
+impl<E, M, A, const N: usize> Generic<[E; N], M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    /// Converts the accessor to `[E; N]` into an array accessor which has `N` elements.
+    ///
+    /// This method does not remap the memory region.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `N == 0`.
+    pub fn as_array(self) -> array::Generic<E, M, A> {
+        assert_ne!(N, 0);
 
-    fn addr<T>(v: &T) -> usize {
-        let v: *const T = v;
+        // SAFETY: `[E; N]` at `self.virt` is in the region of the mapping.
+        unsafe { array::Generic::from_raw_parts(self.virt, N, self.mapping) }
+    }
+}
+impl<T, A> Generic<T, Identity, A>
+where
+    A: AccessorTypeSpecifier,
+{
+    /// Creates a new accessor to an element of type `T` at `offset` bytes from `region_virt`,
+    /// the virtual address of an already mapped region.
+    ///
+    /// This method does not call [`Mapper::map`]. Mapping a region once and creating accessors
+    /// with this method avoids mapping the same region multiple times.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The region containing the value is mapped while the returned accessor lives.
+    /// - The value at `region_virt + offset` is valid.
+    /// - Any other accessors except the one returned by this method must not access the value
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if
+    /// - `region_virt + offset` overflows.
+    /// - `region_virt + offset` is not aligned as the type `T` requires.
+    #[must_use]
+    pub unsafe fn new_in_region(region_virt: usize, offset: usize) -> Self {
+        let virt = region_virt
+            .checked_add(offset)
+            .expect("`region_virt + offset` overflowed.");
+
+        Self::new(virt, Identity)
+    }
+
+    /// Creates a new accessor to an element of type `T` at the virtual address `virt` with
+    /// [`Identity`].
+    ///
+    /// This method is intended to be used in tests, so that they do not need to implement
+    /// [`Mapper`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::new`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `virt` is null or is not aligned as the type `T` requires.
+    #[cfg(any(test, feature = "test-utils"))]
+    #[must_use]
+    pub unsafe fn new_identity(virt: usize) -> Self {
+        Self::new(virt, Identity)
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    /// Reads a value from the address that the accessor points to.
+    ///
+    /// The read is always performed, so this method can be used for the registers whose reads
+    /// have side effects, such as read-to-clear status registers. Use `let _ =` to discard the
+    /// value of such a read explicitly. Use [`Generic::peek`] instead to state that the read has no
+    /// side effects.
+    ///
+    /// Ignoring the read value without `let _ =` is warned.
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    ///
+    /// use accessor::mapper::Identity;
+    /// use accessor::single;
+    ///
+    /// let v = 42_u32;
+    /// let a = unsafe { single::ReadOnly::<u32, _>::new(&v as *const u32 as usize, Identity) };
+    ///
+    /// a.read_volatile();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the `debug-assert-align` feature and debug assertions are enabled, this method panics if
+    /// the address is not aligned as the type `T` requires.
+    #[must_use]
+    pub fn read_volatile(&self) -> T {
+        #[cfg(feature = "debug-assert-align")]
+        debug_assert!(super::is_aligned::<T>(self.virt));
+
+        // SAFETY: `Accessor::new` ensures that `self.virt` is aligned properly.
+        unsafe { volatile::read(self.virt) }
+    }
+
+    /// Reads the value that the accessor points to.
+    ///
+    /// This is the same as [`Generic::read_volatile`].
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.read_volatile()
+    }
+
+    /// Returns a read-only accessor to the field of type `U` at `offset` bytes from the start of
+    /// the value.
+    ///
+    /// The returned accessor borrows `self`, so it cannot outlive this accessor.
+    ///
+    /// ```compile_fail
+    /// use accessor::mapper::Identity;
+    /// use accessor::single;
+    ///
+    /// let v = 42_u32;
+    /// let field = {
+    ///     let a = unsafe { single::ReadOnly::<u32, _>::new(&v as *const u32 as usize, Identity) };
+    ///     unsafe { a.field_at::<u16>(0) }
+    /// };
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the field at `offset` bytes from the start of the value is a
+    /// valid value of `U`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if
+    /// - the field does not fit in the value.
+    /// - the field is not aligned as the type `U` requires.
+    pub unsafe fn field_at<U>(&self, offset: usize) -> Bounded<'_, U, M, marker::ReadOnly> {
+        assert!(offset
+            .checked_add(mem::size_of::<U>())
+            .is_some_and(|end| end <= mem::size_of::<T>()));
+
+        Bounded::new(self.virt + offset)
+    }
+
+    /// The implementation of [`field_accessor!`](crate::field_accessor).
+    ///
+    /// `project` is never called. It is used to infer the type of the field.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `offset` is the offset of the field which `project` returns.
+    #[doc(hidden)]
+    pub unsafe fn __field_of<U>(
+        &self,
+        offset: usize,
+        project: fn(&T) -> &U,
+    ) -> Bounded<'_, U, M, marker::ReadOnly> {
+        let _ = project;
+        self.field_at(offset)
+    }
+
+    /// Returns read-only accessors to the lower part of type `L` at the start of the value, and the
+    /// higher part of type `H` just after it.
+    ///
+    /// This is useful for a register which consists of two halves, such as a 64-bit register
+    /// accessed as two 32-bit ones.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the conditions of [`Generic::field_at`] for both fields.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the fields do not fit in the value or are not aligned properly.
+    pub unsafe fn split_halves<L, H>(
+        &self,
+    ) -> (
+        Bounded<'_, L, M, marker::ReadOnly>,
+        Bounded<'_, H, M, marker::ReadOnly>,
+    )
+    where
+        L: Copy,
+        H: Copy,
+    {
+        (self.field_at(0), self.field_at(mem::size_of::<L>()))
+    }
+
+    /// Reads a value from the address that the accessor points to, and then issues a compiler
+    /// fence with `order`.
+    ///
+    /// The fence prevents the compiler from reordering the subsequent memory accesses before this
+    /// read, as [`atomic::compiler_fence`] does. It does not emit any machine instructions, nor
+    /// does it make the read atomic. No fence is issued if `order` is [`Ordering::Relaxed`].
+    pub fn read_volatile_ordered(&self, order: Ordering) -> T {
+        let v = self.read_volatile();
+        compiler_fence(order);
+        v
+    }
+
+    /// Reads the bytes of the value that the accessor points to.
+    ///
+    /// Each byte is read volatilely one by one.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `N` is not equal to the size of `T`.
+    pub fn read_bytes<const N: usize>(&self) -> [u8; N] {
+        assert_eq!(N, mem::size_of::<T>());
+
+        let mut bytes = [0; N];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            // SAFETY: The `i`th byte is in the memory region of the value.
+            *b = unsafe { volatile::read(self.virt + i) };
+        }
+        bytes
+    }
+
+    /// Reads the value once, and returns an iterator over the positions of the set bits from the
+    /// least significant one.
+    ///
+    /// The value is read only when this method is called, so the yielded positions do not change
+    /// even if the value on memory changes during the iteration.
+    pub fn set_bits(&self) -> impl Iterator<Item = u32>
+    where
+        T: IntReg,
+    {
+        let v = self.read_volatile();
+        (0..T::BITS).filter(move |&n| (v >> n) & T::ONE != T::ZERO)
+    }
+
+    /// Reads the value once, and returns `true` if the `n`th bit from the least significant one is
+    /// set.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `n` is not less than the number of bits of `T`.
+    pub fn test_bit(&self, n: u32) -> bool
+    where
+        T: IntReg,
+    {
+        assert!(n < T::BITS);
+
+        (self.read_volatile() >> n) & T::ONE != T::ZERO
+    }
+
+    /// Reads a value repeatedly until `done` returns `true` for it, and returns the value.
+    ///
+    /// This method reads the value at most `max_iters` times, and calls `tick` with the number of
+    /// the failed attempts between the reads, so that the caller can wait or yield there.
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::Timeout`] if `done` does not return `true` within
+    /// `max_iters` reads.
+    #[allow(clippy::too_many_arguments)] // `done` and `tick` cannot be merged.
+    pub fn poll<F, G>(&self, done: F, mut tick: G, max_iters: usize) -> Result<T, Error>
+    where
+        F: Fn(&T) -> bool,
+        G: FnMut(usize),
+    {
+        for i in 0..max_iters {
+            if i > 0 {
+                tick(i);
+            }
+
+            let v = self.read_volatile();
+            if done(&v) {
+                return Ok(v);
+            }
+        }
+
+        Err(Error::Timeout)
+    }
+
+    /// Reads a value repeatedly until it differs from `sentinel`, and returns the value.
+    ///
+    /// This is useful for the registers which transiently return a sentinel value such as
+    /// `0xFFFF_FFFF`, e.g. while the device is being reset. This method returns `None` if all of
+    /// the `max_attempts` reads return `sentinel`.
+    pub fn read_retry_not(&self, sentinel: T, max_attempts: usize) -> Option<T>
+    where
+        T: PartialEq + Copy,
+    {
+        (0..max_attempts)
+            .map(|_| self.read_volatile())
+            .find(|v| *v != sentinel)
+    }
+
+    /// Reads a value of a natural width from the address that the accessor points to.
+    ///
+    /// This is the same as [`Generic::read_volatile`], but the bound guarantees at compile time
+    /// that `T` is an aligned integer of 1, 2, 4, or 8 bytes, which processors can usually read
+    /// with a single load instruction. Note that Rust itself does not guarantee that a volatile
+    /// read is compiled into a single instruction, and 8-byte reads may be split on 32-bit
+    /// processors.
+    #[must_use]
+    pub fn read_volatile_word(&self) -> T
+    where
+        T: NaturalWidth,
+    {
+        self.read_volatile()
+    }
+
+    /// Reads a value from the address that the accessor points to, stating that the read has no
+    /// side effects.
+    ///
+    /// This is the same as [`Generic::read_volatile`]. Use this method to label the reads of the
+    /// values which do not change by being read, as opposed to read-to-clear registers, so that
+    /// readers of the code can tell that the read can be repeated or omitted safely.
+    #[must_use]
+    pub fn peek(&self) -> T {
+        self.read_volatile()
+    }
+
+    /// Creates an accessor to the value at the physical address `phys_base`, reads the value
+    /// once, and returns the accessor if `expected` returns `true` for the value.
+    ///
+    /// This is useful to check that a device is present before using it. The memory region is
+    /// unmapped if the probe fails.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the same conditions as [`Generic::new`].
+    ///
+    /// # Errors
+    ///
+    /// This method returns [`Error::ProbeFailed`] if `expected` returns `false`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `phys_base` is not aligned as the type `T` requires.
+    pub unsafe fn new_and_probe<F>(phys_base: usize, mapper: M, expected: F) -> Result<Self, Error>
+    where
+        T: Copy,
+        F: Fn(T) -> bool,
+    {
+        let a = Self::new(phys_base, mapper);
+
+        if expected(a.read_volatile()) {
+            Ok(a)
+        } else {
+            // `a` is dropped here, and the region is unmapped.
+            Err(Error::ProbeFailed)
+        }
+    }
+
+    /// Alias of [`Generic::read_volatile`].
+    #[deprecated(since = "0.3.1", note = "use `read_volatile`")]
+    pub fn read(&self) -> T {
+        self.read_volatile()
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    /// Writes a value to the address that the accessor points to.
+    ///
+    /// # Panics
+    ///
+    /// If the `debug-assert-align` feature and debug assertions are enabled, this method panics if
+    /// the address is not aligned as the type `T` requires.
+    pub fn write_volatile(&mut self, v: T) {
+        #[cfg(feature = "debug-assert-align")]
+        debug_assert!(super::is_aligned::<T>(self.virt));
+
+        // SAFETY: `Accessor::new` ensures that `self.virt` is aligned properly.
+        unsafe {
+            volatile::write(self.virt, v);
+        }
+    }
+
+    /// Issues a compiler fence with `order`, and then writes a value to the address that the
+    /// accessor points to.
+    ///
+    /// The fence prevents the compiler from reordering the preceding memory accesses after this
+    /// write, as [`atomic::compiler_fence`] does. It does not emit any machine instructions, nor
+    /// does it make the write atomic. No fence is issued if `order` is [`Ordering::Relaxed`].
+    pub fn write_volatile_ordered(&mut self, v: T, order: Ordering) {
+        compiler_fence(order);
+        self.write_volatile(v);
+    }
+
+    /// Alias of [`Generic::write_volatile`].
+    #[deprecated(since = "0.3.1", note = "use `write_volatile`")]
+    pub fn write(&mut self, v: T) {
+        self.write_volatile(v);
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: Readable + Writable,
+{
+    /// Updates a value that the accessor points by reading it, modifying it, and writing it.
+    ///
+    /// Note that some MMIO regions (e.g. the Command Ring Pointer field of the Command
+    /// Ring Control Register of the xHCI) may return 0 regardless of the actual values of the
+    /// fields. For these regions, this operation should be called only once.
+    pub fn update_volatile<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        let mut v = self.read_volatile();
+        f(&mut v);
+        self.write_volatile(v);
+    }
+
+    /// Updates a value that the accessor points by reading it, modifying it, and writing it only if
+    /// `f` returns `Ok`.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the error `f` returns. The value is not written in this case.
+    pub fn try_update<E, F>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnOnce(&mut T) -> Result<(), E>,
+    {
+        let mut v = self.read_volatile();
+        f(&mut v)?;
+        self.write_volatile(v);
+
+        Ok(())
+    }
+
+    /// Writes a value to the address that the accessor points to, and reads it back to check that
+    /// the register stores it.
+    ///
+    /// This catches registers which ignore or alter the written values, such as write-1-to-clear
+    /// bits, during development. This method is the same as [`Generic::write_volatile`] unless
+    /// the `debug-assert-writes` feature and debug assertions are enabled.
+    ///
+    /// # Panics
+    ///
+    /// If the `debug-assert-writes` feature and debug assertions are enabled, this method panics
+    /// with both values if the value read back is not equal to `v`.
+    pub fn write_volatile_checked(&mut self, v: T)
+    where
+        T: PartialEq + fmt::Debug,
+    {
+        // SAFETY: `v` is never dropped through this bitwise copy.
+        #[cfg(feature = "debug-assert-writes")]
+        let written = mem::ManuallyDrop::new(unsafe { ptr::read(&v) });
+
+        self.write_volatile(v);
+
+        #[cfg(feature = "debug-assert-writes")]
+        if cfg!(debug_assertions) {
+            // SAFETY: `Accessor::new` ensures that `self.virt` is aligned properly.
+            let read = mem::ManuallyDrop::new(unsafe { volatile::read::<T>(self.virt) });
+            assert_eq!(
+                *read, *written,
+                "The value read back differs from the written one."
+            );
+        }
+    }
+
+    /// Writes each of `patterns` and reads it back to test whether the value is stored correctly.
+    ///
+    /// The original value is restored before this method returns.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the first pattern whose read-back value differs from the written one.
+    /// The remaining patterns are not written.
+    pub fn self_test(&mut self, patterns: &[T]) -> Result<(), T>
+    where
+        T: Copy + PartialEq,
+    {
+        let original = self.read_volatile();
+
+        let r = patterns.iter().copied().try_for_each(|p| {
+            self.write_volatile(p);
+
+            if self.read_volatile() == p {
+                Ok(())
+            } else {
+                Err(p)
+            }
+        });
+
+        self.write_volatile(original);
+
+        r
+    }
+
+    /// Updates a value that the accessor points by reading it, modifying it, and writing it.
+    ///
+    /// This method returns the value before the modification.
+    pub fn update_returning_old<U>(&mut self, f: U) -> T
+    where
+        T: Copy,
+        U: FnOnce(&mut T),
+    {
+        let old = self.read_volatile();
+        let mut new = old;
+        f(&mut new);
+        self.write_volatile(new);
+
+        old
+    }
+
+    /// Updates a value that the accessor points by reading it and modifying it, and writes it only
+    /// if `f` changed it.
+    ///
+    /// This method returns `true` if the value is written.
+    pub fn update_if_changed<U>(&mut self, f: U) -> bool
+    where
+        T: Copy + PartialEq,
+        U: FnOnce(&mut T),
+    {
+        let old = self.read_volatile();
+        let mut new = old;
+        f(&mut new);
+
+        let changed = new != old;
+        if changed {
+            self.write_volatile(new);
+        }
+        changed
+    }
+
+    /// Exchanges the value with the one that `other` points to.
+    ///
+    /// This method reads both values and then writes each of them to the other. It is not atomic.
+    pub fn swap_with<M2, B>(&mut self, other: &mut Generic<T, M2, B>)
+    where
+        T: Copy,
+        M2: Mapper,
+        B: Readable + Writable,
+    {
+        let mine = self.read_volatile();
+        let theirs = other.read_volatile();
+
+        self.write_volatile(theirs);
+        other.write_volatile(mine);
+    }
+
+    /// Reads the value, writes zero, and returns the read value.
+    ///
+    /// This method supports both kinds of status registers which are cleared by accesses:
+    /// - For write-to-clear registers, writing zero clears the register. Note that registers
+    ///   whose bits are cleared by writing one (write-1-to-clear) need the read value written back
+    ///   instead.
+    /// - For read-to-clear registers, the read clears the register, and the write of zero is
+    ///   redundant but harmless.
+    pub fn read_and_clear(&mut self) -> T
+    where
+        T: IntReg,
+    {
+        let v = self.read_volatile();
+        self.write_volatile(T::ZERO);
+        v
+    }
+
+    /// Clears the bits set in `clear_mask`, and then sets the bits set in `set_mask`, by reading
+    /// and writing the value once.
+    ///
+    /// The written value is `(value & !clear_mask) | set_mask`.
+    pub fn modify_bits(&mut self, clear_mask: T, set_mask: T)
+    where
+        T: IntReg,
+    {
+        self.update_volatile(|x| *x = (*x & !clear_mask) | set_mask);
+    }
+
+    /// Adds `v` to the value, wrapping around at the boundary of the type.
+    pub fn add_assign(&mut self, v: T)
+    where
+        T: IntReg,
+    {
+        self.update_volatile(|x| *x = x.wrapping_add(v));
+    }
+
+    /// Subtracts `v` from the value, wrapping around at the boundary of the type.
+    pub fn sub_assign(&mut self, v: T)
+    where
+        T: IntReg,
+    {
+        self.update_volatile(|x| *x = x.wrapping_sub(v));
+    }
+
+    /// Sets the value to the bitwise OR of the value and `v`.
+    pub fn or_assign(&mut self, v: T)
+    where
+        T: IntReg,
+    {
+        self.update_volatile(|x| *x = *x | v);
+    }
+
+    /// Sets the value to the bitwise AND of the value and `v`.
+    pub fn and_assign(&mut self, v: T)
+    where
+        T: IntReg,
+    {
+        self.update_volatile(|x| *x = *x & v);
+    }
+
+    /// Sets the value to the bitwise XOR of the value and `v`.
+    pub fn xor_assign(&mut self, v: T)
+    where
+        T: IntReg,
+    {
+        self.update_volatile(|x| *x = *x ^ v);
+    }
+
+    /// Alias of [`Generic::update_volatile`].
+    #[deprecated(since = "0.3.1", note = "use `update_volatile`")]
+    pub fn update<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        self.update_volatile(f);
+    }
+}
+impl<T, M, A> fmt::Debug for Generic<T, M, A>
+where
+    T: fmt::Debug,
+    M: Mapper,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.read_volatile())
+    }
+}
+macro_rules! impl_fmt {
+    ($($tr:ident),*) => {
+        $(
+            impl<T, M, A> fmt::$tr for Generic<T, M, A>
+            where
+                T: fmt::$tr,
+                M: Mapper,
+                A: Readable,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$tr::fmt(&self.read_volatile(), f)
+                }
+            }
+        )*
+    };
+}
+impl_fmt!(LowerHex, UpperHex, Binary);
+
+impl<T, M, A> PartialEq for Generic<T, M, A>
+where
+    T: PartialEq,
+    M: Mapper,
+    A: Readable,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.read_volatile().eq(&other.read_volatile())
+    }
+}
+impl<T, M, A> Eq for Generic<T, M, A>
+where
+    T: Eq,
+    M: Mapper,
+    A: Readable,
+{
+}
+impl<T, M, A> PartialOrd for Generic<T, M, A>
+where
+    T: PartialOrd,
+    M: Mapper,
+    A: Readable,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.read_volatile().partial_cmp(&other.read_volatile())
+    }
+}
+impl<T, M, A> Ord for Generic<T, M, A>
+where
+    T: Ord,
+    M: Mapper,
+    A: Readable,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.read_volatile().cmp(&other.read_volatile())
+    }
+}
+impl<T, M, A> Hash for Generic<T, M, A>
+where
+    T: Hash,
+    M: Mapper,
+    A: Readable,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.read_volatile().hash(state);
+    }
+}
+
+/// An accessor to a single value whose lifetime is bounded by the accessor it is derived from.
+///
+/// Unlike [`Generic`], this accessor does not own a mapping. It borrows the mapping of another
+/// accessor, so it never calls [`Mapper::unmap`] and cannot outlive the original accessor.
+pub struct Bounded<'a, T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    virt: usize,
+    _lifetime: PhantomData<&'a M>,
+    _marker: PhantomData<T>,
+    _readable_writable: PhantomData<A>,
+}
+impl<T, M, A> Bounded<'_, T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    /// Creates an accessor to the value at the virtual address `virt`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the value at `virt` is valid and mapped while the returned
+    /// accessor lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `virt` is not aligned as the type `T` requires.
+    pub(crate) unsafe fn new(virt: usize) -> Self {
+        assert!(super::is_aligned::<T>(virt));
+
+        Self {
+            virt,
+            _lifetime: PhantomData,
+            _marker: PhantomData,
+            _readable_writable: PhantomData,
+        }
+    }
+}
+impl<T, M, A> Bounded<'_, T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    /// Reads a value from the address that the accessor points to.
+    #[must_use]
+    pub fn read_volatile(&self) -> T {
+        // SAFETY: `Bounded::new` ensures that `self.virt` is aligned properly.
+        unsafe { volatile::read(self.virt) }
+    }
+
+    /// Reads the value that the accessor points to.
+    ///
+    /// This is the same as [`Bounded::read_volatile`].
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.read_volatile()
+    }
+}
+impl<T, M, A> Bounded<'_, T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    /// Writes a value to the address that the accessor points to.
+    pub fn write_volatile(&mut self, v: T) {
+        // SAFETY: `Bounded::new` ensures that `self.virt` is aligned properly.
+        unsafe {
+            volatile::write(self.virt, v);
+        }
+    }
+}
+impl<T, M, A> Bounded<'_, T, M, A>
+where
+    M: Mapper,
+    A: Readable + Writable,
+{
+    /// Updates a value that the accessor points by reading it, modifying it, and writing it.
+    pub fn update_volatile<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        let mut v = self.read_volatile();
+        f(&mut v);
+        self.write_volatile(v);
+    }
+}
+impl<T, M, A> fmt::Debug for Bounded<'_, T, M, A>
+where
+    T: fmt::Debug,
+    M: Mapper,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.read_volatile())
+    }
+}
+
+/// Returns a read-only accessor to a field of the value which a readable accessor points to.
+///
+/// `field_accessor!(a, S, f)` returns the same accessor as `a.field_at::<F>(offset)`, where `F` is
+/// the type of the field `f` of the struct `S`, and `offset` is the offset of the field. Unlike
+/// [`Generic::field_at`], the type and the offset are derived from the struct definition, so this
+/// macro is safe to use. `S` must be the type of the value `a` points to.
+///
+/// # Examples
+///
+/// ```
+/// use accessor::mapper::Identity;
+/// use accessor::{field_accessor, single};
+///
+/// #[repr(C)]
+/// struct Regs {
+///     status: u32,
+///     control: u16,
+/// }
+///
+/// let v = Regs {
+///     status: 1,
+///     control: 2,
+/// };
+/// let a = unsafe { single::ReadOnly::<Regs, _>::new(&v as *const Regs as usize, Identity) };
+///
+/// assert_eq!(field_accessor!(a, Regs, control).read_volatile(), 2);
+/// ```
+#[macro_export]
+macro_rules! field_accessor {
+    ($parent:expr, $type:ty, $field:tt) => {{
+        let parent = &$parent;
+
+        // SAFETY: The offset is the one of the field which the closure returns, and the field of a
+        // valid value is valid.
+        unsafe {
+            parent.__field_of(::core::mem::offset_of!($type, $field), |v: &$type| {
+                &v.$field
+            })
+        }
+    }};
+}
+
+/// An accessor to a single value which may not be aligned as the type `T` requires.
+///
+/// Some device memory has fields which are not naturally aligned. This accessor reads and writes
+/// the value byte by byte, so it never accesses memory with a misaligned pointer. Unlike
+/// [`Generic`], it provides only [`Unaligned::read_unaligned`] and [`Unaligned::write_unaligned`].
+///
+/// The region is unmapped when the accessor is dropped.
+pub struct Unaligned<T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    virt: usize,
+    // Unmaps the region when the accessor is dropped.
+    _mapping: Mapping<M>,
+    _marker: PhantomData<T>,
+    _readable_writable: PhantomData<A>,
+}
+impl<T, M, A> Unaligned<T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    /// Creates a new accessor to an element of type `T` at the physical address `phys_base`, which
+    /// may not be aligned as the type `T` requires.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The value at the physical address `phys_base` is valid.
+    /// - Any other accessors except the one returned by this method must not access the value
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `T` is a zero-sized type.
+    pub unsafe fn new(phys_base: usize, mapper: M) -> Self {
+        assert_ne!(mem::size_of::<T>(), 0, "`T` must not be a zero-sized type.");
+
+        let mapping = Mapping::new(phys_base, mem::size_of::<T>(), mapper);
+
+        Self {
+            virt: mapping.virt(),
+            _mapping: mapping,
+            _marker: PhantomData,
+            _readable_writable: PhantomData,
+        }
+    }
+
+    /// Returns the virtual address of the value.
+    pub fn virt(&self) -> usize {
+        self.virt
+    }
+}
+impl<T, M, A> Unaligned<T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    /// Reads a value from the address that the accessor points to.
+    ///
+    /// The value is read byte by byte with volatile reads, so this method issues multiple bus
+    /// transactions.
+    #[must_use]
+    pub fn read_unaligned(&self) -> T
+    where
+        T: Copy,
+    {
+        let mut v = mem::MaybeUninit::<T>::uninit();
+        let dst = v.as_mut_ptr().cast::<mem::MaybeUninit<u8>>();
+
+        for i in 0..mem::size_of::<T>() {
+            // SAFETY: Both addresses are in the value, and bytes do not need any alignment.
+            unsafe {
+                dst.add(i).write(ptr::read_volatile(
+                    (self.virt + i) as *const mem::MaybeUninit<u8>,
+                ));
+            }
+        }
+
+        // SAFETY: All bytes are copied from a valid value.
+        unsafe { v.assume_init() }
+    }
+}
+impl<T, M, A> Unaligned<T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    /// Writes a value to the address that the accessor points to.
+    ///
+    /// The value is written byte by byte with volatile writes, so this method issues multiple bus
+    /// transactions.
+    pub fn write_unaligned(&mut self, v: T) {
+        let v = mem::ManuallyDrop::new(v);
+        let src: *const T = &*v;
+        let src = src.cast::<mem::MaybeUninit<u8>>();
+
+        for i in 0..mem::size_of::<T>() {
+            // SAFETY: Both addresses are in the value, and bytes do not need any alignment. The
+            // bytes are copied as `MaybeUninit<u8>`, so padding bytes are never read as `u8`.
+            unsafe {
+                ptr::write_volatile(
+                    (self.virt + i) as *mut mem::MaybeUninit<u8>,
+                    src.add(i).read(),
+                );
+            }
+        }
+    }
+}
+impl<T, M, A> fmt::Debug for Unaligned<T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unaligned")
+            .field("virt", &self.virt)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A wrapper of a readable and writable accessor which allows writing through a shared reference.
+///
+/// This type is not [`Sync`], so the value can be read and written only in a single thread.
+/// However, the caller still must ensure that no other accessor, including the ones in interrupt
+/// handlers, accesses the same value while this wrapper is used, because this type does not
+/// provide any exclusion.
+pub struct Cell<T, M>
+where
+    M: Mapper,
+{
+    inner: UnsafeCell<ReadWrite<T, M>>,
+}
+impl<T, M> Cell<T, M>
+where
+    M: Mapper,
+{
+    /// Wraps `accessor`.
+    pub fn new(accessor: ReadWrite<T, M>) -> Self {
+        Self {
+            inner: UnsafeCell::new(accessor),
+        }
+    }
+
+    /// Unwraps the accessor.
+    pub fn into_inner(self) -> ReadWrite<T, M> {
+        self.inner.into_inner()
+    }
+
+    /// Reads a value from the address that the accessor points to.
+    #[must_use]
+    pub fn get(&self) -> T {
+        // SAFETY: `Cell` is not `Sync`, and no reference to the accessor escapes from the methods,
+        // so no other reference to it exists.
+        unsafe { (*self.inner.get()).read_volatile() }
+    }
+
+    /// Writes a value to the address that the accessor points to.
+    pub fn set(&self, v: T) {
+        // SAFETY: `Cell` is not `Sync`, and no reference to the accessor escapes from the methods,
+        // so no other reference to it exists.
+        unsafe { (*self.inner.get()).write_volatile(v) }
+    }
+}
+impl<T, M> From<ReadWrite<T, M>> for Cell<T, M>
+where
+    M: Mapper,
+{
+    fn from(accessor: ReadWrite<T, M>) -> Self {
+        Self::new(accessor)
+    }
+}
+impl<T, M> fmt::Debug for Cell<T, M>
+where
+    T: fmt::Debug,
+    M: Mapper,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.get())
+    }
+}
+
+fn compiler_fence(order: Ordering) {
+    // `atomic::compiler_fence` panics if `order` is `Relaxed`.
+    if order != Ordering::Relaxed {
+        atomic::compiler_fence(order);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::volatile::Register;
+    use core::{cell::Cell, num::NonZeroUsize};
+
+    extern crate std;
+    use std::format;
+
+    struct M;
+    impl Mapper for M {
+        unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+            NonZeroUsize::new(phys_start).unwrap()
+        }
+
+        fn unmap(&mut self, _: usize, _: usize) {}
+    }
+
+    #[test]
+    fn test_read_volatile() {
+        let v: u32 = 42;
+        let a = unsafe { ReadWrite::<u32, M>::new(addr(&v), M) };
+
+        assert_eq!(a.read_volatile(), 42);
+    }
+
+    #[test]
+    fn test_write_volatile() {
+        let mut v: u32 = 0;
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr_mut(&mut v), M) };
+
+        a.write_volatile(42);
+        assert_eq!(v, 42);
+    }
+
+    #[test]
+    fn test_update_volatile() {
+        let mut v: u32 = 42;
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr_mut(&mut v), M) };
+
+        a.update_volatile(|v| *v *= 2);
+        assert_eq!(v, 84);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_not_aligned() {
+        let v: u32 = 42;
+
+        let _ = unsafe { ReadWrite::<u32, M>::new(addr(&v) + 1, M) };
+    }
+
+    #[test]
+    fn test_err_not_aligned() {
+        let v: u32 = 42;
+
+        let r = unsafe { ReadWrite::<u32, M>::try_new(addr(&v) + 1, M) };
+        assert_eq!(
+            r,
+            Err(Error::NotAligned {
+                alignment: mem::align_of::<u32>(),
+                address: addr(&v) + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_into_read_only() {
+        let v: u32 = 42;
+        let a = unsafe { ReadWrite::<u32, M>::new(addr(&v), M) };
+        let a = a.into_read_only();
+
+        assert_eq!(a.read_volatile(), 42);
+    }
+
+    #[test]
+    fn test_write_volatile_checked() {
+        let reg = Register::new(0);
+        let mut a = unsafe { ReadWrite::<u32, M>::new(reg.addr(), M) };
+
+        a.write_volatile_checked(42);
+        assert_eq!(a.read_volatile(), 42);
+    }
+
+    #[cfg(all(feature = "debug-assert-writes", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "The value read back differs from the written one.")]
+    fn test_write_volatile_checked_dropped_write() {
+        // The register drops writes.
+        let reg = Register::new(0).on_write(|_| 0);
+        let mut a = unsafe { ReadWrite::<u32, M>::new(reg.addr(), M) };
+
+        a.write_volatile_checked(42);
+    }
+
+    #[test]
+    fn test_new_identity() {
+        let mut v: u32 = 42;
+        let mut a = unsafe { ReadWrite::<u32, _>::new_identity(addr_mut(&mut v)) };
+
+        assert_eq!(a.read_volatile(), 42);
+
+        a.write_volatile(3);
+        assert_eq!(v, 3);
+    }
+
+    #[test]
+    fn test_as_array() {
+        let v: [u32; 4] = [1, 2, 3, 4];
+        let a = unsafe { ReadOnly::<[u32; 4], M>::new(addr(&v), M) };
+        let a = a.as_array();
+
+        assert_eq!(a.len(), 4);
+        assert_eq!(a.read_volatile_at(2), 3);
+    }
+
+    #[test]
+    fn test_new_in_region() {
+        #[repr(C)]
+        struct Region {
+            header: u32,
+            entries: [u16; 4],
+            footer: u32,
+        }
+
+        struct Counting<'a> {
+            maps: &'a Cell<usize>,
+        }
+        impl Mapper for Counting<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                self.maps.set(self.maps.get() + 1);
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, _: usize, _: usize) {}
+        }
+
+        let region = Region {
+            header: 1,
+            entries: [2, 3, 4, 5],
+            footer: 6,
+        };
+        let maps = Cell::new(0);
+        let mut mapper = Counting { maps: &maps };
+
+        let virt = unsafe { mapper.map(addr(&region), mem::size_of::<Region>()) }.get();
+
+        let header = unsafe { ReadOnly::<u32, _>::new_in_region(virt, 0) };
+        let entries = unsafe { array::ReadOnly::<u16, _>::new_in_region(virt, 4, 4) };
+        let footer = unsafe { ReadOnly::<u32, _>::new_in_region(virt, 12) };
+
+        assert_eq!(header.read_volatile(), 1);
+        assert_eq!(entries.read_volatile_at(3), 5);
+        assert_eq!(footer.read_volatile(), 6);
+        assert_eq!(maps.get(), 1);
+    }
+
+    #[test]
+    fn test_field_at() {
+        #[repr(C)]
+        struct S {
+            a: u16,
+            b: u16,
+        }
+
+        let v = S { a: 1, b: 2 };
+        let a = unsafe { ReadOnly::<S, M>::new(addr(&v), M) };
+        let b = unsafe { a.field_at::<u16>(2) };
+
+        assert_eq!(b.read_volatile(), 2);
+    }
+
+    #[test]
+    fn test_field_accessor() {
+        #[repr(C)]
+        struct S {
+            a: u8,
+            b: u32,
+            c: u16,
+        }
+
+        let v = S { a: 1, b: 2, c: 3 };
+        let a = unsafe { ReadOnly::<S, M>::new(addr(&v), M) };
+
+        assert_eq!(crate::field_accessor!(a, S, a).read_volatile(), 1);
+        assert_eq!(crate::field_accessor!(a, S, b).read_volatile(), 2);
+        assert_eq!(crate::field_accessor!(a, S, c).read_volatile(), 3);
+    }
+
+    #[test]
+    fn test_value() {
+        #[repr(C)]
+        struct S {
+            a: u16,
+            b: u16,
+        }
+
+        let v = S { a: 1, b: 2 };
+        let a = unsafe { ReadOnly::<S, M>::new(addr(&v), M) };
+        let b = unsafe { a.field_at::<u16>(2) };
+
+        assert_eq!(b.value(), b.read_volatile());
+
+        let v: u32 = 42;
+        let a = unsafe { ReadOnly::<u32, M>::new(addr(&v), M) };
+
+        assert_eq!(a.value(), a.read_volatile());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_field_at_out_of_value() {
+        let v: u32 = 42;
+        let a = unsafe { ReadOnly::<u32, M>::new(addr(&v), M) };
+
+        let _ = unsafe { a.field_at::<u16>(4) };
+    }
+
+    #[test]
+    fn test_volatile_ordered() {
+        let mut v: u32 = 0;
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr_mut(&mut v), M) };
+
+        for order in [Ordering::Relaxed, Ordering::Release, Ordering::SeqCst] {
+            a.write_volatile_ordered(42, order);
+            assert_eq!(a.read_volatile_ordered(Ordering::Acquire), 42);
+
+            a.write_volatile_ordered(0, order);
+            assert_eq!(a.read_volatile_ordered(Ordering::Relaxed), 0);
+        }
+    }
+
+    #[test]
+    fn test_debug_describe() {
+        struct Describing;
+        impl Mapper for Describing {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, _: usize, _: usize) {}
+
+            fn describe(&self, _: usize) -> Option<&'static str> {
+                Some("MSI table")
+            }
+        }
+
+        let v: u32 = 42;
+        let a = unsafe { ReadOnly::<u32, M>::new(addr(&v), M) };
+        let b = unsafe { ReadOnly::<u32, _>::new(addr(&v), Describing) };
+
+        assert_eq!(a.debug_describe(), None);
+        assert_eq!(b.debug_describe(), Some("MSI table"));
+    }
+
+    #[test]
+    fn test_try_offset() {
+        let v: [u16; 2] = [1, 2];
+        let a = unsafe { ReadOnly::<[u16; 2], M>::new(addr(&v), M) };
+        let b = unsafe { a.try_offset::<u16>(2) }.unwrap();
+
+        assert_eq!(b.read_volatile(), 2);
+    }
+
+    #[test]
+    fn test_err_try_offset_overflow() {
+        // The accessor is never read.
+        let a = unsafe { ReadOnly::<u32, _>::new(usize::MAX - 3, Identity) };
+
+        let r = unsafe { a.try_offset::<u32>(8) };
+        assert_eq!(r.map(|_| ()), Err(Error::SizeOverflow));
+
+        let r = unsafe { a.try_offset::<u32>(0) };
+        assert_eq!(r.map(|_| ()), Err(Error::SizeOverflow));
+    }
+
+    #[test]
+    fn test_err_try_offset_not_aligned() {
+        let v: [u16; 2] = [1, 2];
+        let a = unsafe { ReadOnly::<[u16; 2], M>::new(addr(&v), M) };
+
+        let r = unsafe { a.try_offset::<u16>(1) };
+        assert_eq!(
+            r.map(|_| ()),
+            Err(Error::NotAligned {
+                alignment: mem::align_of::<u16>(),
+                address: addr(&v) + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_bytes() {
+        let v: u32 = 0x1234_5678;
+        let a = unsafe { ReadOnly::<u32, M>::new(addr(&v), M) };
+
+        assert_eq!(a.read_bytes::<4>(), v.to_ne_bytes());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_read_bytes_size_mismatch() {
+        let v: u32 = 0x1234_5678;
+        let a = unsafe { ReadOnly::<u32, M>::new(addr(&v), M) };
+
+        let _ = a.read_bytes::<2>();
+    }
+
+    #[test]
+    fn test_leak() {
+        struct Tracking<'a> {
+            unmapped: &'a Cell<bool>,
+        }
+        impl Mapper for Tracking<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, _: usize, _: usize) {
+                self.unmapped.set(true);
+            }
+        }
+
+        let unmapped = Cell::new(false);
+        let v: u32 = 42;
+        let a = unsafe {
+            ReadOnly::<u32, _>::new(
+                addr(&v),
+                Tracking {
+                    unmapped: &unmapped,
+                },
+            )
+        };
+
+        assert_eq!(a.leak(), addr(&v));
+        assert!(!unmapped.get());
+    }
+
+    #[test]
+    fn test_set_bits() {
+        let v: u32 = 1 << 31 | 1 << 3 | 1;
+        let a = unsafe { ReadOnly::<u32, M>::new(addr(&v), M) };
+
+        let mut bits = a.set_bits();
+        assert_eq!(bits.next(), Some(0));
+        assert_eq!(bits.next(), Some(3));
+        assert_eq!(bits.next(), Some(31));
+        assert_eq!(bits.next(), None);
+    }
+
+    #[test]
+    fn test_from_non_null() {
+        let v: u32 = 42;
+        let a = unsafe { ReadOnly::<u32, _>::from_non_null(NonNull::from(&v), Identity) };
+
+        assert_eq!(a.read_volatile(), 42);
+    }
+
+    #[test]
+    fn test_update_if_changed() {
+        let mut v: u32 = 42;
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr_mut(&mut v), M) };
+
+        assert!(!a.update_if_changed(|v| *v |= 2));
+        assert_eq!(a.read_volatile(), 42);
+
+        assert!(a.update_if_changed(|v| *v += 1));
+        assert_eq!(v, 43);
+    }
+
+    #[test]
+    fn test_cell() {
+        fn set_through_shared(c: &super::Cell<u32, M>, v: u32) {
+            c.set(v);
+        }
+
+        let mut v = 0_u32;
+        let c = super::Cell::from(unsafe { ReadWrite::<u32, _>::new(addr_mut(&mut v), M) });
+
+        set_through_shared(&c, 42);
+        assert_eq!(c.get(), 42);
+
+        c.set(c.get() + 1);
+        assert_eq!(c.into_inner().read_volatile(), 43);
+    }
+
+    #[test]
+    fn test_poll() {
+        let v = Cell::new(0_u32);
+        let a = unsafe { ReadOnly::<u32, _>::new(v.as_ptr() as usize, M) };
+
+        let mut ticks = 0;
+        let r = a.poll(
+            |&x| x == 3,
+            |_| {
+                ticks += 1;
+                v.set(v.get() + 1);
+            },
+            10,
+        );
+
+        assert_eq!(r, Ok(3));
+        assert_eq!(ticks, 3);
+    }
+
+    #[test]
+    fn test_poll_timeout() {
+        let v = 0_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr(&v), M) };
+
+        let mut ticks = 0;
+        let r = a.poll(|&x| x == 1, |_| ticks += 1, 4);
+
+        assert_eq!(r, Err(Error::Timeout));
+        assert_eq!(ticks, 3);
+    }
+
+    #[cfg(all(feature = "debug-assert-align", debug_assertions))]
+    #[test]
+    #[should_panic]
+    fn test_debug_assert_align() {
+        let v = [0_u32; 2];
+
+        // Bypass the alignment check of the constructors to make a misaligned accessor.
+        let a = unsafe {
+            ReadOnly::<u16, _>::from_raw_parts(
+                addr(&v) + 1,
+                Mapping::new(addr(&v), mem::size_of_val(&v), M),
+            )
+        };
+
+        let _ = a.read_volatile();
+    }
+
+    #[test]
+    fn test_arithmetic_assign() {
+        let mut v = 0b11_u32;
+        let mut a = unsafe { ReadWrite::<u32, _>::new(addr_mut(&mut v), M) };
+
+        a.add_assign(5);
+        assert_eq!(a.read_volatile(), 8);
+
+        a.or_assign(0b100);
+        assert_eq!(a.read_volatile(), 0b1100);
+
+        a.sub_assign(13);
+        assert_eq!(a.read_volatile(), u32::MAX);
+    }
+
+    #[test]
+    fn test_swap_with() {
+        let mut x = 1_u32;
+        let mut y = 2_u32;
+
+        let mut a = unsafe { ReadWrite::<u32, _>::new(addr_mut(&mut x), M) };
+        let mut b = unsafe { ReadWrite::<u32, _>::new(addr_mut(&mut y), M) };
+
+        a.swap_with(&mut b);
+
+        assert_eq!(a.read_volatile(), 2);
+        assert_eq!(b.read_volatile(), 1);
+    }
+
+    #[test]
+    fn test_with_drop_hook() {
+        use core::sync::atomic::AtomicUsize;
+
+        static VIRT: AtomicUsize = AtomicUsize::new(0);
+        static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+        let v = 0_u64;
+        let a = unsafe { ReadOnly::<u64, _>::new(addr(&v), M) }.with_drop_hook(|virt, bytes| {
+            VIRT.store(virt, Ordering::Relaxed);
+            BYTES.store(bytes, Ordering::Relaxed);
+        });
+
+        assert_eq!(BYTES.load(Ordering::Relaxed), 0);
+
+        drop(a);
+        assert_eq!(VIRT.load(Ordering::Relaxed), addr(&v));
+        assert_eq!(BYTES.load(Ordering::Relaxed), 8);
+    }
+
+    #[test]
+    fn test_following_array() {
+        #[repr(C)]
+        struct Table {
+            header: u32,
+            entries: [u16; 3],
+        }
+
+        let t = Table {
+            header: 3,
+            entries: [1, 2, 3],
+        };
+
+        let h = unsafe { ReadOnly::<u32, _>::new(addr(&t.header), M) };
+        assert_eq!(h.read_volatile(), 3);
+
+        let a = unsafe { h.following_array::<u16>(3) };
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.read_volatile_at(0), 1);
+        assert_eq!(a.read_volatile_at(2), 3);
+    }
+
+    #[test]
+    fn test_drop_hook_following_array() {
+        use core::sync::atomic::AtomicUsize;
+
+        static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+        let t: [u32; 3] = [3, 1, 2];
+        let h = unsafe { ReadOnly::<u32, _>::new(addr(&t), M) }.with_drop_hook(|_, bytes| {
+            BYTES.fetch_add(bytes, Ordering::Relaxed);
+        });
+
+        let a = unsafe { h.following_array::<u32>(2) };
+        assert_eq!(BYTES.load(Ordering::Relaxed), 4);
+
+        drop(a);
+        assert_eq!(BYTES.load(Ordering::Relaxed), 12);
+    }
+
+    #[test]
+    fn test_drop_hook_into_identity() {
+        use core::sync::atomic::AtomicUsize;
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let v = 0_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr(&v), M) }.with_drop_hook(|_, _| {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+        });
+
+        drop(a.into_identity());
+        assert_eq!(CALLS.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_split_halves() {
+        let v = 0x1234_5678_9abc_def0_u64;
+        let a = unsafe { ReadOnly::<u64, _>::new(addr(&v), M) };
+
+        let (lo, hi) = unsafe { a.split_halves::<u32, u32>() };
+
+        if cfg!(target_endian = "little") {
+            assert_eq!(lo.read_volatile(), 0x9abc_def0);
+            assert_eq!(hi.read_volatile(), 0x1234_5678);
+        } else {
+            assert_eq!(lo.read_volatile(), 0x1234_5678);
+            assert_eq!(hi.read_volatile(), 0x9abc_def0);
+        }
+    }
+
+    #[test]
+    fn test_into_identity() {
+        struct Counting<'a> {
+            unmapped: &'a Cell<usize>,
+        }
+        impl Mapper for Counting<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, _: usize, _: usize) {
+                self.unmapped.set(self.unmapped.get() + 1);
+            }
+        }
+
+        let unmapped = Cell::new(0);
+        let v = 42_u32;
+        let a = unsafe {
+            ReadOnly::<u32, _>::new(
+                addr(&v),
+                Counting {
+                    unmapped: &unmapped,
+                },
+            )
+        };
+
+        let a = a.into_identity();
+        assert_eq!(a.read_volatile(), 42);
+
+        drop(a);
+        assert_eq!(unmapped.get(), 0);
+    }
+
+    #[test]
+    fn test_read_retry_not() {
+        let v = 42_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr(&v), M) };
+
+        assert_eq!(a.read_retry_not(u32::MAX, 3), Some(42));
+        assert_eq!(a.read_retry_not(42, 3), None);
+    }
+
+    #[test]
+    fn test_read_retry_not_after_sentinels() {
+        // The register returns the sentinel twice while the device is being reset.
+        let reg = Register::new(0).on_read(|n| if n < 2 { u32::MAX } else { 42 });
+        let a = unsafe { ReadOnly::<u32, _>::new(reg.addr(), M) };
+
+        assert_eq!(a.read_retry_not(u32::MAX, 5), Some(42));
+        assert_eq!(reg.reads(), 3);
+    }
+
+    #[test]
+    fn test_virt() {
+        let v = 0_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr(&v), Identity) };
+
+        assert_eq!(a.virt(), addr(&v));
+    }
+
+    #[test]
+    fn test_test_bit() {
+        let v = 0b1000_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr(&v), M) };
+
+        assert!(a.test_bit(3));
+        assert!(!a.test_bit(2));
+    }
+
+    #[test]
+    fn test_try_update() {
+        let mut v = 1_u32;
+        let mut a = unsafe { ReadWrite::<u32, _>::new(addr_mut(&mut v), M) };
+
+        assert_eq!(
+            a.try_update(|x| x.checked_add(1).map(|y| *x = y).ok_or(())),
+            Ok(())
+        );
+        assert_eq!(a.read_volatile(), 2);
+
+        let r = a.try_update(|x| {
+            *x = 42;
+            Err("failed")
+        });
+        assert_eq!(r, Err("failed"));
+        assert_eq!(a.read_volatile(), 2);
+    }
+
+    #[test]
+    fn test_read_volatile_word() {
+        fn read<T: NaturalWidth>(v: &T) -> T {
+            unsafe { ReadOnly::<T, _>::new(addr(v), M) }.read_volatile_word()
+        }
+
+        assert_eq!(read(&0x12_u8), 0x12);
+        assert_eq!(read(&0x1234_u16), 0x1234);
+        assert_eq!(read(&0x1234_5678_u32), 0x1234_5678);
+        assert_eq!(read(&0x1234_5678_9abc_def0_u64), 0x1234_5678_9abc_def0);
+        assert_eq!(read(&usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn test_peek() {
+        let v = 42_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr(&v), M) };
+
+        assert_eq!(a.peek(), a.read_volatile());
+    }
+
+    #[test]
+    fn test_modify_bits() {
+        let mut v = 0b1101_u32;
+        let mut a = unsafe { ReadWrite::<u32, _>::new(addr_mut(&mut v), M) };
+
+        a.modify_bits(0b1100, 0b0010);
+        assert_eq!(a.read_volatile(), 0b0011);
+    }
+
+    #[test]
+    fn test_bit_width() {
+        assert_eq!(ReadOnly::<u32, M>::bit_width(), 32);
+        assert_eq!(ReadWrite::<u8, M>::bit_width(), 8);
+    }
+
+    #[test]
+    fn test_view_as_array_of() {
+        #[repr(align(16))]
+        struct Aligned([u32; 4]);
+
+        let v = Aligned([1, 2, 3, 4]);
+        let a = unsafe { ReadOnly::<u128, _>::new(addr(&v), M) };
+
+        let a = unsafe { a.view_as_array_of::<u32>() };
+        assert_eq!(a.len(), 4);
+        assert!(a.into_iter().eq(v.0.iter().copied()));
+    }
+
+    #[test]
+    fn test_read_and_clear() {
+        let mut v = 0b1010_u32;
+        let mut a = unsafe { ReadWrite::<u32, _>::new(addr_mut(&mut v), M) };
+
+        assert_eq!(a.read_and_clear(), 0b1010);
+        assert_eq!(v, 0);
+    }
+
+    #[test]
+    fn test_fmt_radix() {
+        let v = 0xab_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr(&v), M) };
+
+        assert_eq!(format!("{:#x}", a), "0xab");
+        assert_eq!(format!("{:X}", a), "AB");
+        assert_eq!(format!("{:010b}", a), "0010101011");
+    }
+
+    #[test]
+    fn test_new_and_probe() {
+        struct Counting<'a> {
+            unmapped: &'a Cell<usize>,
+        }
+        impl Mapper for Counting<'_> {
+            unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+                NonZeroUsize::new(phys_start).unwrap()
+            }
+
+            fn unmap(&mut self, _: usize, _: usize) {
+                self.unmapped.set(self.unmapped.get() + 1);
+            }
+        }
+
+        let unmapped = Cell::new(0);
+        let v = 0xffff_ffff_u32;
+
+        let a = unsafe {
+            ReadOnly::<u32, _>::new_and_probe(
+                addr(&v),
+                Counting {
+                    unmapped: &unmapped,
+                },
+                |x| x != 0xffff_ffff,
+            )
+        };
+        assert_eq!(a.unwrap_err(), Error::ProbeFailed);
+        assert_eq!(unmapped.get(), 1);
+
+        let a = unsafe {
+            ReadOnly::<u32, _>::new_and_probe(
+                addr(&v),
+                Counting {
+                    unmapped: &unmapped,
+                },
+                |x| x == 0xffff_ffff,
+            )
+        };
+        assert!(a.is_ok());
+        assert_eq!(unmapped.get(), 1);
+    }
+
+    #[test]
+    fn test_unaligned() {
+        let mut bytes = [0_u8; 8];
+        let virt = addr_mut(&mut bytes) + 1;
+
+        let mut a = unsafe { Unaligned::<u32, _, marker::ReadWrite>::new(virt, M) };
+        a.write_unaligned(0x1234_5678);
+
+        assert_eq!(a.read_unaligned(), 0x1234_5678);
+        assert_eq!(bytes[1..5], 0x1234_5678_u32.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_try_new_zero_sized() {
+        let v = ();
+        let a = unsafe { ReadOnly::<(), _>::try_new(addr(&v), M) };
+
+        assert_eq!(a.unwrap_err(), Error::ZeroSized);
+    }
+
+    #[test]
+    fn test_into_read_write() {
+        let mut v = 1_u32;
+        let a = unsafe { ReadOnly::<u32, _>::new(addr_mut(&mut v), M) };
+
+        let mut a = unsafe { a.into_read_write() };
+        a.write_volatile(2);
+        assert_eq!(a.read_volatile(), 2);
+    }
+
+    #[test]
+    fn test_update_returning_old() {
+        let mut v: u32 = 42;
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr_mut(&mut v), M) };
+
+        assert_eq!(a.update_returning_old(|v| *v |= 1), 42);
+        assert_eq!(a.read_volatile(), 43);
+
+        drop(a);
+        assert_eq!(v, 43);
+    }
+
+    #[test]
+    fn test_points_to_same() {
+        let reg = Register::array(&[1, 1]);
+        let a = unsafe { ReadWrite::<u32, M>::new(reg.addr(), M) };
+        let b = unsafe { ReadWrite::<u32, M>::new(reg.addr(), M) };
+        let c = unsafe { ReadWrite::<u32, M>::new(reg.addr() + 4, M) };
+
+        assert!(a.points_to_same(&b));
+        assert!(!a.points_to_same(&c));
+        assert_eq!(reg.reads(), 0);
+    }
+
+    #[test]
+    fn test_self_test() {
+        let mut v: u32 = 42;
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr_mut(&mut v), M) };
+
+        assert_eq!(
+            a.self_test(&[0, 0x5555_5555, 0xAAAA_AAAA, u32::MAX]),
+            Ok(())
+        );
+        assert_eq!(a.read_volatile(), 42);
+    }
+
+    #[test]
+    fn test_self_test_mismatch() {
+        // The upper 16 bits of the register are not implemented and always read as 0.
+        let reg = Register::new(0xABCD).on_write(|v| v & 0xFFFF);
+        let mut a = unsafe { ReadWrite::<u32, M>::new(reg.addr(), M) };
+
+        assert_eq!(a.self_test(&[0x1234]), Ok(()));
+        assert_eq!(a.self_test(&[0x1234, u32::MAX, 0]), Err(u32::MAX));
+        assert_eq!(a.read_volatile(), 0xABCD);
+    }
+
+    fn addr<T>(v: &T) -> usize {
+        let v: *const T = v;
+
+        v as usize
+    }
+
+    fn addr_mut<T>(v: &mut T) -> usize {
+        let v: *mut T = v;
 
         v as usize
     }
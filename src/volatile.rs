@@ -0,0 +1,165 @@
+//! Volatile accesses to memory.
+//!
+//! Accessors read and write values through these functions. In tests, the accesses to the
+//! registers emulated by `Register` are observed and altered as a device does.
+
+use core::ptr;
+
+/// Reads a value from `addr` with a volatile read.
+///
+/// # Safety
+///
+/// The caller must ensure the same conditions as [`ptr::read_volatile`] for `addr`.
+pub(crate) unsafe fn read<T>(addr: usize) -> T {
+    #[cfg(test)]
+    emulated::before_read(addr);
+
+    ptr::read_volatile(addr as *const T)
+}
+
+/// Writes `v` to `addr` with a volatile write.
+///
+/// # Safety
+///
+/// The caller must ensure the same conditions as [`ptr::write_volatile`] for `addr`.
+pub(crate) unsafe fn write<T>(addr: usize, v: T) {
+    ptr::write_volatile(addr as *mut T, v);
+
+    #[cfg(test)]
+    emulated::after_write(addr);
+}
+
+#[cfg(test)]
+pub(crate) use emulated::Register;
+
+#[cfg(test)]
+mod emulated {
+    extern crate std;
+
+    use core::{cell::Cell, ops::Range, ptr};
+    use std::{boxed::Box, cell::RefCell, rc::Rc, thread_local, vec::Vec};
+
+    thread_local! {
+        static REGISTERS: RefCell<Vec<Rc<State>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// `u32` registers which count the reads and alter the values as a device does.
+    ///
+    /// The registers are emulated only on the thread creating them, so tests running in parallel
+    /// do not affect each other.
+    #[derive(Debug)]
+    pub(crate) struct Register {
+        state: Rc<State>,
+    }
+    impl Register {
+        /// Creates a register holding `init`.
+        pub(crate) fn new(init: u32) -> Self {
+            Self::array(&[init])
+        }
+
+        /// Creates contiguous registers holding `init`.
+        pub(crate) fn array(init: &[u32]) -> Self {
+            let words = Box::into_raw(init.to_vec().into_boxed_slice());
+            let state = Rc::new(State {
+                words,
+                reads: Cell::new(0),
+                on_read: Cell::new(None),
+                on_write: Cell::new(None),
+            });
+
+            REGISTERS.with(|r| r.borrow_mut().push(Rc::clone(&state)));
+
+            Self { state }
+        }
+
+        /// Makes the `n`th read (0-based) of the registers return `f(n)`.
+        pub(crate) fn on_read(self, f: OnRead) -> Self {
+            self.state.on_read.set(Some(f));
+            self
+        }
+
+        /// Makes the registers hold `f(v)` after `v` is written.
+        pub(crate) fn on_write(self, f: OnWrite) -> Self {
+            self.state.on_write.set(Some(f));
+            self
+        }
+
+        /// Returns the address of the first register.
+        pub(crate) fn addr(&self) -> usize {
+            self.state.range().start
+        }
+
+        /// Returns the number of reads of the registers so far.
+        pub(crate) fn reads(&self) -> usize {
+            self.state.reads.get()
+        }
+    }
+    impl Drop for Register {
+        fn drop(&mut self) {
+            REGISTERS.with(|r| r.borrow_mut().retain(|s| !Rc::ptr_eq(s, &self.state)));
+
+            // SAFETY: `words` is created by `Box::into_raw`, and no accessor uses the registers
+            // after they are dropped.
+            drop(unsafe { Box::from_raw(self.state.words) });
+        }
+    }
+
+    type OnRead = fn(usize) -> u32;
+    type OnWrite = fn(u32) -> u32;
+
+    #[derive(Debug)]
+    struct State {
+        words: *mut [u32],
+        reads: Cell<usize>,
+        on_read: Cell<Option<OnRead>>,
+        on_write: Cell<Option<OnWrite>>,
+    }
+    impl State {
+        fn range(&self) -> Range<usize> {
+            let start = self.words.cast::<u32>() as usize;
+
+            // SAFETY: `words` is valid until the register is dropped.
+            start..start + core::mem::size_of_val(unsafe { &*self.words })
+        }
+
+        /// Returns the register containing `addr`.
+        fn word(&self, addr: usize) -> *mut u32 {
+            let start = self.range().start;
+
+            (start + (addr - start) / 4 * 4) as *mut u32
+        }
+    }
+
+    pub(super) fn before_read(addr: usize) {
+        with_state(addr, |s| {
+            let n = s.reads.get();
+            s.reads.set(n + 1);
+
+            if let Some(f) = s.on_read.get() {
+                // SAFETY: The register is valid until it is dropped.
+                unsafe { ptr::write_volatile(s.word(addr), f(n)) };
+            }
+        });
+    }
+
+    pub(super) fn after_write(addr: usize) {
+        with_state(addr, |s| {
+            if let Some(f) = s.on_write.get() {
+                let word = s.word(addr);
+
+                // SAFETY: The register is valid until it is dropped.
+                unsafe { ptr::write_volatile(word, f(ptr::read_volatile(word))) };
+            }
+        });
+    }
+
+    fn with_state(addr: usize, f: impl FnOnce(&State)) {
+        // `try_with` fails while the thread-local storage is being destroyed, in which case no
+        // register is emulated.
+        let _ = REGISTERS.try_with(|r| {
+            if let Some(s) = r.borrow().iter().find(|s| s.range().contains(&addr)) {
+                f(s);
+            }
+        });
+    }
+}